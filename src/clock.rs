@@ -0,0 +1,32 @@
+use std::cell::Cell;
+
+/// Counts the cycles a Network has run.  Anything that changes over
+/// time, like a learning schedule, holds on to the network's clock
+/// rather than counting cycles itself
+pub struct Clock {
+    tick: Cell<u64>,
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock {
+    pub fn new() -> Clock {
+        Clock { tick: Cell::new(0) }
+    }
+
+    pub fn get_tick(&self) -> u64 {
+        self.tick.get()
+    }
+
+    pub fn advance(&self) {
+        self.tick.set(self.tick.get() + 1);
+    }
+
+    pub fn reset(&self) {
+        self.tick.set(0);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::neuron::{Neuronic, NeuronicInput, SynapticType};
+use crate::rng::Rng;
+use crate::weight_init::WeightInit;
+use std::rc::Rc;
+
+/// Which inputs each neuron gets a synapse from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Pattern {
+    /// Every neuron from every input
+    AllToAll,
+    /// Every neuron from each input with the given probability
+    Sparse { probability: f32 },
+    /// Every neuron from the given number of distinct inputs, chosen at random
+    FixedFanIn(usize),
+    /// The nth neuron from the nth input.  There must be
+    /// as many inputs as neurons
+    OneToOne,
+}
+
+/// Builds the synapses between a group of inputs and a group of
+/// neurons in one go.  All of the randomness, which inputs are
+/// chosen, the starting weights and the synaptic types, comes from
+/// the Rng passed to connect, so the same seed builds the same network
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Connector {
+    pattern: Pattern,
+    init: WeightInit,
+    inhibitory_fraction: f32,
+}
+
+impl Connector {
+    /// Every synapse is excitatory, see with_inhibitory_fraction
+    pub fn new(pattern: Pattern, init: WeightInit) -> Connector {
+        if let Pattern::Sparse { probability } = pattern {
+            if !(0. ..=1.).contains(&probability) {
+                panic!(
+                    "Connection probability {} out of bounds!  Must be in [0.0, 1.0]",
+                    probability
+                );
+            }
+        }
+
+        Connector {
+            pattern,
+            init,
+            inhibitory_fraction: 0.0,
+        }
+    }
+
+    /// Each input is made inhibitory with this probability, and
+    /// excitatory otherwise, and every synapse from an input has its
+    /// sign, so the connections keep to Dale's law
+    pub fn with_inhibitory_fraction(mut self, inhibitory_fraction: f32) -> Connector {
+        if !(0. ..=1.).contains(&inhibitory_fraction) {
+            panic!(
+                "Inhibitory fraction {} out of bounds!  Must be in [0.0, 1.0]",
+                inhibitory_fraction
+            );
+        }
+
+        self.inhibitory_fraction = inhibitory_fraction;
+        self
+    }
+
+    pub fn get_pattern(&self) -> Pattern {
+        self.pattern
+    }
+
+    pub fn get_init(&self) -> WeightInit {
+        self.init
+    }
+
+    pub fn get_inhibitory_fraction(&self) -> f32 {
+        self.inhibitory_fraction
+    }
+
+    /// Creates the synapses and returns how many were created.
+    /// Neurons are connected in order, and each neuron's synapses
+    /// are created in the order of its inputs
+    pub fn connect<N: Neuronic + ?Sized>(
+        &self,
+        rng: &mut Rng,
+        inputs: &[Rc<dyn NeuronicInput>],
+        neurons: &[Rc<N>],
+    ) -> usize {
+        if self.pattern == Pattern::OneToOne && inputs.len() != neurons.len() {
+            panic!(
+                "Can't connect {} inputs one to one with {} neurons",
+                inputs.len(),
+                neurons.len()
+            );
+        }
+
+        let synaptic_types: Vec<SynapticType> = inputs
+            .iter()
+            .map(|_| {
+                if rng.chance(self.inhibitory_fraction) {
+                    SynapticType::Inhibitory
+                } else {
+                    SynapticType::Excitatory
+                }
+            })
+            .collect();
+
+        let mut count = 0;
+
+        for (neuron_index, neuron) in neurons.iter().enumerate() {
+            let chosen: Vec<usize> = match self.pattern {
+                Pattern::AllToAll => (0..inputs.len()).collect(),
+                Pattern::Sparse { probability } => (0..inputs.len())
+                    .filter(|_| rng.chance(probability))
+                    .collect(),
+                Pattern::FixedFanIn(fan_in) => {
+                    let mut chosen = rng.sample_indices(inputs.len(), fan_in);
+                    chosen.sort_unstable();
+                    chosen
+                }
+                Pattern::OneToOne => vec![neuron_index],
+            };
+
+            let fan_in = chosen.len();
+
+            for input_index in chosen {
+                neuron.create_synapse(
+                    self.init.sample(rng, fan_in),
+                    synaptic_types[input_index],
+                    Rc::clone(&inputs[input_index]),
+                );
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+#[cfg(test)]
+mod connectivity_tests;
@@ -0,0 +1,197 @@
+use crate::connectivity::{Connector, Pattern};
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::rng::Rng;
+use crate::weight_init::WeightInit;
+use std::rc::Rc;
+
+fn sensors(count: usize) -> Vec<Rc<dyn NeuronicInput>> {
+    (0..count)
+        .map(|_| Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>)
+        .collect()
+}
+
+fn neurons(count: usize) -> Vec<Rc<Neuron>> {
+    (0..count)
+        .map(|_| Rc::new(Neuron::new(10., 8., 0.1)))
+        .collect()
+}
+
+#[test]
+fn test_all_to_all() {
+    let inputs = sensors(6);
+    let layer = neurons(4);
+
+    let connector = Connector::new(Pattern::AllToAll, WeightInit::Constant(2.));
+    let count = connector.connect(&mut Rng::new(0), &inputs, &layer);
+
+    assert_eq!(count, 24);
+    for neuron in layer.iter() {
+        assert_eq!(neuron.get_weights(), vec![2.; 6]);
+        assert_eq!(
+            neuron.get_synaptic_types(),
+            vec![SynapticType::Excitatory; 6]
+        );
+    }
+}
+
+#[test]
+fn test_sparse() {
+    let inputs = sensors(100);
+    let layer = neurons(10);
+
+    let connector = Connector::new(
+        Pattern::Sparse { probability: 0.2 },
+        WeightInit::Constant(1.),
+    );
+    let count = connector.connect(&mut Rng::new(0), &inputs, &layer);
+
+    assert_eq!(
+        count,
+        layer.iter().map(|n| n.get_synapse_count()).sum::<usize>()
+    );
+    assert!(count > 150 && count < 250, "count was {}", count);
+
+    // Probabilities of 0 and 1 are exact
+    let empty = neurons(3);
+    let connector = Connector::new(
+        Pattern::Sparse { probability: 0. },
+        WeightInit::Constant(1.),
+    );
+    assert_eq!(connector.connect(&mut Rng::new(0), &inputs, &empty), 0);
+
+    let full = neurons(3);
+    let connector = Connector::new(
+        Pattern::Sparse { probability: 1. },
+        WeightInit::Constant(1.),
+    );
+    assert_eq!(connector.connect(&mut Rng::new(0), &inputs, &full), 300);
+}
+
+#[test]
+fn test_fixed_fan_in() {
+    let inputs = sensors(20);
+    let layer = neurons(5);
+
+    // Scaling by fan-in sees the 4 synapses each neuron gets
+    let connector = Connector::new(
+        Pattern::FixedFanIn(4),
+        WeightInit::FanIn {
+            total: 10.,
+            spread: 0.,
+        },
+    );
+    assert_eq!(connector.connect(&mut Rng::new(0), &inputs, &layer), 20);
+
+    for neuron in layer.iter() {
+        assert_eq!(neuron.get_weights(), vec![2.5; 4]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_fan_in_too_large() {
+    let connector = Connector::new(Pattern::FixedFanIn(4), WeightInit::Constant(1.));
+    connector.connect(&mut Rng::new(0), &sensors(3), &neurons(1));
+}
+
+#[test]
+fn test_one_to_one() {
+    let inputs: Vec<Rc<NeuronicSensor>> = (0..3).map(|_| Rc::new(NeuronicSensor::new())).collect();
+    let layer = neurons(3);
+
+    let connector = Connector::new(Pattern::OneToOne, WeightInit::Constant(10.));
+    connector.connect(
+        &mut Rng::new(0),
+        &inputs
+            .iter()
+            .map(|sensor| Rc::clone(sensor) as Rc<dyn NeuronicInput>)
+            .collect::<Vec<_>>(),
+        &layer,
+    );
+
+    for (i, sensor) in inputs.iter().enumerate() {
+        sensor.set_measure(0.1 * (i + 1) as f32);
+    }
+
+    for (i, neuron) in layer.iter().enumerate() {
+        assert_eq!(neuron.get_synapse_count(), 1);
+        assert_eq!(
+            neuron.run_static_cycle(ChargeCycle::Even),
+            0.1 * (i + 1) as f32
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_one_to_one_mismatch() {
+    let connector = Connector::new(Pattern::OneToOne, WeightInit::Constant(1.));
+    connector.connect(&mut Rng::new(0), &sensors(3), &neurons(2));
+}
+
+#[test]
+fn test_inhibitory_fraction() {
+    let inputs = sensors(100);
+    let layer = neurons(10);
+
+    let connector =
+        Connector::new(Pattern::AllToAll, WeightInit::Constant(1.)).with_inhibitory_fraction(0.25);
+    connector.connect(&mut Rng::new(0), &inputs, &layer);
+
+    // Each input drives synapses of one sign
+    let synaptic_types = layer[0].get_synaptic_types();
+    for neuron in layer.iter() {
+        assert_eq!(neuron.get_synaptic_types(), synaptic_types);
+    }
+
+    let inhibitory = synaptic_types
+        .iter()
+        .filter(|synaptic_type| **synaptic_type == SynapticType::Inhibitory)
+        .count();
+    assert!(
+        inhibitory > 15 && inhibitory < 35,
+        "{} inhibitory inputs",
+        inhibitory
+    );
+}
+
+#[test]
+fn test_seeded_connections() {
+    let build = |seed: u64| {
+        let layer = neurons(5);
+        let connector = Connector::new(
+            Pattern::Sparse { probability: 0.5 },
+            WeightInit::Uniform { min: 1., max: 4. },
+        )
+        .with_inhibitory_fraction(0.2);
+
+        connector.connect(&mut Rng::new(seed), &sensors(10), &layer);
+        layer
+            .iter()
+            .map(|neuron| (neuron.get_weights(), neuron.get_synaptic_types()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(build(9), build(9));
+    assert_ne!(build(9), build(10));
+}
+
+#[test]
+fn test_dyn_neurons() {
+    let layer: Vec<Rc<dyn Neuronic>> = vec![
+        Rc::new(Neuron::new(1., 8., 0.1)),
+        Rc::new(Neuron::new(1., 8., 0.1)),
+    ];
+
+    let connector = Connector::new(Pattern::AllToAll, WeightInit::Constant(1.));
+    assert_eq!(connector.connect(&mut Rng::new(0), &sensors(3), &layer), 6);
+}
+
+#[test]
+#[should_panic]
+fn test_probability_out_of_bounds() {
+    Connector::new(
+        Pattern::Sparse { probability: 1.5 },
+        WeightInit::Constant(1.),
+    );
+}
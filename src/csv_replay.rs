@@ -0,0 +1,350 @@
+//! Feeding a network from a local CSV file, one row per step.
+//! The parser only handles plain numeric CSV: fields are split on
+//! commas and trimmed, and quoted fields aren't supported
+
+use crate::neuron::NeuronicInput;
+use crate::sensor_array::SensorArray;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    /// A field that isn't a finite number.  Lines and columns count from 1
+    Parse {
+        line: usize,
+        column: usize,
+        field: String,
+    },
+    /// A row with a different number of fields than the first row
+    RowLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A header name that isn't in the file
+    UnknownColumn(String),
+    /// A column index past the end of the rows
+    MissingColumn {
+        column: usize,
+        count: usize,
+    },
+    /// A Normalization::Range that isn't finite, or whose min isn't below its max
+    EmptyRange {
+        min: f32,
+        max: f32,
+    },
+    /// A value of an input column that isn't a measure,
+    /// with Normalization::None
+    OutOfBounds {
+        column: usize,
+        value: f32,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(error) => write!(f, "Couldn't read CSV: {}", error),
+            CsvError::Parse {
+                line,
+                column,
+                field,
+            } => write!(
+                f,
+                "Line {}, column {}: {:?} isn't a finite number",
+                line, column, field
+            ),
+            CsvError::RowLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} fields, expected {}",
+                line, found, expected
+            ),
+            CsvError::UnknownColumn(name) => write!(f, "No column named {:?}", name),
+            CsvError::MissingColumn { column, count } => write!(
+                f,
+                "No column at index {}, dataset has {} columns",
+                column, count
+            ),
+            CsvError::EmptyRange { min, max } => {
+                write!(f, "Normalization range [{}, {}] is empty", min, max)
+            }
+            CsvError::OutOfBounds { column, value } => write!(
+                f,
+                "Column {}: measure {} out of bounds!  Must be between 0.0 and 1.0",
+                column, value
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<io::Error> for CsvError {
+    fn from(error: io::Error) -> CsvError {
+        CsvError::Io(error)
+    }
+}
+
+/// The numeric rows of a CSV file
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvDataset {
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<f32>>,
+}
+
+impl CsvDataset {
+    pub fn from_path<P: AsRef<Path>>(path: P, has_header: bool) -> Result<CsvDataset, CsvError> {
+        CsvDataset::parse(&fs::read_to_string(path)?, has_header)
+    }
+
+    /// Blank lines are skipped
+    pub fn parse(text: &str, has_header: bool) -> Result<CsvDataset, CsvError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let header = if has_header {
+            lines.next().map(|(_, line)| {
+                line.split(',')
+                    .map(|name| name.trim().to_string())
+                    .collect()
+            })
+        } else {
+            None
+        };
+
+        let mut expected = header.as_ref().map(|header: &Vec<String>| header.len());
+        let mut rows = Vec::new();
+
+        for (line_number, line) in lines {
+            let row = line
+                .split(',')
+                .enumerate()
+                .map(|(column, field)| {
+                    // f32 parses "inf" and "NaN", which can't be normalized
+                    match field.trim().parse::<f32>() {
+                        Ok(value) if value.is_finite() => Ok(value),
+                        _ => Err(CsvError::Parse {
+                            line: line_number,
+                            column: column + 1,
+                            field: field.trim().to_string(),
+                        }),
+                    }
+                })
+                .collect::<Result<Vec<f32>, CsvError>>()?;
+
+            match expected {
+                Some(expected) if expected != row.len() => {
+                    return Err(CsvError::RowLength {
+                        line: line_number,
+                        expected,
+                        found: row.len(),
+                    })
+                }
+                _ => expected = Some(row.len()),
+            }
+
+            rows.push(row);
+        }
+
+        Ok(CsvDataset { header, rows })
+    }
+
+    pub fn get_header(&self) -> Option<&[String]> {
+        self.header.as_deref()
+    }
+
+    /// Index of a column by its name in the header
+    pub fn get_column_index(&self, name: &str) -> Result<usize, CsvError> {
+        self.header
+            .as_ref()
+            .and_then(|header| header.iter().position(|column| column == name))
+            .ok_or_else(|| CsvError::UnknownColumn(name.to_string()))
+    }
+
+    pub fn get_rows(&self) -> &[Vec<f32>] {
+        &self.rows
+    }
+
+    pub fn get_row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Taken from the header if there are no rows
+    pub fn get_column_count(&self) -> usize {
+        match (self.rows.first(), self.header.as_ref()) {
+            (Some(row), _) => row.len(),
+            (None, Some(header)) => header.len(),
+            (None, None) => 0,
+        }
+    }
+}
+
+/// How the values of an input column are mapped into [0, 1]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Normalization {
+    /// Values are already measures.  Any that aren't are an
+    /// error when the replay is created
+    None,
+    /// Each column is scaled by its own minimum and
+    /// maximum over the whole dataset
+    MinMax,
+    /// Every column is scaled from [min, max], and clamped to it.
+    /// min must be below max
+    Range(f32, f32),
+}
+
+/// Replays a CsvDataset into a SensorArray, a row at a time.
+/// Input columns are fed, in the order given, to the channels of the
+/// array, and label columns are returned as they are, for example as
+/// targets for a Supervisor
+pub struct CsvReplay {
+    dataset: CsvDataset,
+    input_columns: Vec<usize>,
+    label_columns: Vec<usize>,
+    scales: Vec<(f32, f32)>,
+    looping: bool,
+    next_row: usize,
+    sensors: Rc<SensorArray>,
+}
+
+impl CsvReplay {
+    /// Columns are indices into the dataset's rows, counting from 0
+    pub fn new(
+        dataset: CsvDataset,
+        input_columns: Vec<usize>,
+        label_columns: Vec<usize>,
+        normalization: Normalization,
+    ) -> Result<CsvReplay, CsvError> {
+        let column_count = dataset.get_column_count();
+
+        if let Some(column) = input_columns
+            .iter()
+            .chain(label_columns.iter())
+            .find(|column| **column >= column_count)
+        {
+            return Err(CsvError::MissingColumn {
+                column: *column,
+                count: column_count,
+            });
+        }
+
+        if let Normalization::Range(min, max) = normalization {
+            if !min.is_finite() || !max.is_finite() || min >= max {
+                return Err(CsvError::EmptyRange { min, max });
+            }
+        }
+
+        let scales = input_columns
+            .iter()
+            .map(|column| {
+                let values = dataset.rows.iter().map(|row| row[*column]);
+
+                match normalization {
+                    Normalization::None => match values.clone().find(|v| !(0. ..=1.).contains(v)) {
+                        Some(value) => Err(CsvError::OutOfBounds {
+                            column: *column,
+                            value,
+                        }),
+                        None => Ok((0.0, 1.0)),
+                    },
+                    Normalization::MinMax => Ok((
+                        values.clone().fold(f32::INFINITY, f32::min),
+                        values.fold(f32::NEG_INFINITY, f32::max),
+                    )),
+                    Normalization::Range(min, max) => Ok((min, max)),
+                }
+            })
+            .collect::<Result<Vec<(f32, f32)>, CsvError>>()?;
+
+        let sensors = Rc::new(SensorArray::new(input_columns.len()));
+
+        Ok(CsvReplay {
+            dataset,
+            input_columns,
+            label_columns,
+            scales,
+            looping: false,
+            next_row: 0,
+            sensors,
+        })
+    }
+
+    /// Starts over from the first row after the last one
+    pub fn with_looping(mut self) -> CsvReplay {
+        self.looping = true;
+        self
+    }
+
+    pub fn get_sensors(&self) -> &Rc<SensorArray> {
+        &self.sensors
+    }
+
+    /// The channels of the sensor array, one per input column
+    pub fn get_inputs(&self) -> Vec<Rc<dyn NeuronicInput>> {
+        self.sensors.get_inputs()
+    }
+
+    pub fn get_dataset(&self) -> &CsvDataset {
+        &self.dataset
+    }
+
+    /// Index of the row the next call to next_row will present
+    pub fn get_position(&self) -> usize {
+        self.next_row
+    }
+
+    pub fn reset(&mut self) {
+        self.next_row = 0;
+    }
+
+    /// Sets the sensors to the next row and returns its labels, or
+    /// returns None once every row has been presented, unless looping
+    pub fn next_row(&mut self) -> Option<Vec<f32>> {
+        if self.next_row >= self.dataset.rows.len() {
+            if !self.looping || self.dataset.rows.is_empty() {
+                return None;
+            }
+            self.next_row = 0;
+        }
+
+        let row = &self.dataset.rows[self.next_row];
+
+        let measures: Vec<f32> = self
+            .input_columns
+            .iter()
+            .zip(self.scales.iter())
+            .map(|(column, (min, max))| {
+                if max > min {
+                    ((row[*column] - min) / (max - min)).clamp(0.0, 1.0)
+                } else {
+                    // A constant column carries no information
+                    0.0
+                }
+            })
+            .collect();
+        self.sensors.set_all(&measures);
+
+        let labels = self
+            .label_columns
+            .iter()
+            .map(|column| row[*column])
+            .collect();
+
+        self.next_row += 1;
+        Some(labels)
+    }
+}
+
+#[cfg(test)]
+mod csv_replay_tests;
@@ -0,0 +1,184 @@
+use crate::csv_replay::{CsvDataset, CsvError, CsvReplay, Normalization};
+use crate::neuron::ChargeCycle;
+use crate::test_utils::cmp_f32;
+use std::fs;
+
+const WEATHER: &str = "temperature, humidity, rain
+10, 0.2, 0
+30, 0.8, 1
+
+20, 0.5, 0
+";
+
+#[test]
+fn test_parse() {
+    let dataset = CsvDataset::parse(WEATHER, true).unwrap();
+
+    assert_eq!(
+        dataset.get_header().unwrap(),
+        &["temperature", "humidity", "rain"]
+    );
+    assert_eq!(dataset.get_row_count(), 3);
+    assert_eq!(dataset.get_column_count(), 3);
+    assert_eq!(dataset.get_rows()[1], vec![30., 0.8, 1.]);
+    assert_eq!(dataset.get_column_index("rain").unwrap(), 2);
+
+    match dataset.get_column_index("wind") {
+        Err(CsvError::UnknownColumn(name)) => assert_eq!(name, "wind"),
+        _ => panic!("wind isn't a column"),
+    }
+
+    // A header with no rows still has columns
+    let empty = CsvDataset::parse("a, b\n", true).unwrap();
+    assert_eq!(empty.get_row_count(), 0);
+    assert_eq!(empty.get_column_count(), 2);
+
+    let mut replay = CsvReplay::new(empty, vec![0], vec![1], Normalization::MinMax).unwrap();
+    assert_eq!(replay.next_row(), None);
+
+    let no_header = CsvDataset::parse("1,2\n3,4", false).unwrap();
+    assert_eq!(no_header.get_header(), None);
+    assert_eq!(no_header.get_row_count(), 2);
+}
+
+#[test]
+fn test_parse_errors() {
+    match CsvDataset::parse("1,2\n3,x", false) {
+        Err(CsvError::Parse {
+            line,
+            column,
+            field,
+        }) => {
+            assert_eq!((line, column), (2, 2));
+            assert_eq!(field, "x");
+        }
+        _ => panic!("x isn't a number"),
+    }
+
+    match CsvDataset::parse("a\n1\ninf\n", true) {
+        Err(CsvError::Parse { line, field, .. }) => {
+            assert_eq!(line, 3);
+            assert_eq!(field, "inf");
+        }
+        _ => panic!("inf isn't a finite number"),
+    }
+
+    match CsvDataset::parse("NaN", false) {
+        Err(CsvError::Parse { field, .. }) => assert_eq!(field, "NaN"),
+        _ => panic!("NaN isn't a finite number"),
+    }
+
+    match CsvDataset::parse("a,b\n1,2\n3", true) {
+        Err(CsvError::RowLength {
+            line,
+            expected,
+            found,
+        }) => assert_eq!((line, expected, found), (3, 2, 1)),
+        _ => panic!("Last row is too short"),
+    }
+
+    match CsvDataset::from_path("/this/file/does/not/exist.csv", false) {
+        Err(CsvError::Io(_)) => (),
+        _ => panic!("File doesn't exist"),
+    }
+}
+
+#[test]
+fn test_from_path() {
+    let path = std::env::temp_dir().join(format!("aesir3_csv_{}.csv", std::process::id()));
+    fs::write(&path, WEATHER).unwrap();
+
+    let dataset = CsvDataset::from_path(&path, true);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(dataset.unwrap(), CsvDataset::parse(WEATHER, true).unwrap());
+}
+
+#[test]
+fn test_replay() {
+    let dataset = CsvDataset::parse(WEATHER, true).unwrap();
+    let rain = dataset.get_column_index("rain").unwrap();
+
+    let mut replay =
+        CsvReplay::new(dataset, vec![0, 1], vec![rain], Normalization::MinMax).unwrap();
+    let inputs = replay.get_inputs();
+    assert_eq!(inputs.len(), 2);
+
+    // Temperature is scaled from [10, 30], humidity from [0.2, 0.8]
+    assert_eq!(replay.next_row(), Some(vec![0.]));
+    cmp_f32(inputs[0].get_measure(ChargeCycle::Even), 0.);
+    cmp_f32(inputs[1].get_measure(ChargeCycle::Even), 0.);
+
+    assert_eq!(replay.next_row(), Some(vec![1.]));
+    cmp_f32(inputs[0].get_measure(ChargeCycle::Even), 1.);
+    cmp_f32(inputs[1].get_measure(ChargeCycle::Even), 1.);
+
+    assert_eq!(replay.next_row(), Some(vec![0.]));
+    cmp_f32(replay.get_sensors().get(0), 0.5);
+    cmp_f32(replay.get_sensors().get(1), 0.5);
+
+    // Without looping, the replay ends and the sensors keep the last row
+    assert_eq!(replay.next_row(), None);
+    cmp_f32(replay.get_sensors().get(0), 0.5);
+
+    replay.reset();
+    assert_eq!(replay.get_position(), 0);
+    assert_eq!(replay.next_row(), Some(vec![0.]));
+}
+
+#[test]
+fn test_looping_replay() {
+    let dataset = CsvDataset::parse("0.1\n0.2", false).unwrap();
+    let mut replay = CsvReplay::new(dataset, vec![0], vec![], Normalization::None)
+        .unwrap()
+        .with_looping();
+
+    let measures: Vec<f32> = (0..5)
+        .map(|_| {
+            replay.next_row().unwrap();
+            replay.get_sensors().get(0)
+        })
+        .collect();
+    assert_eq!(measures, vec![0.1, 0.2, 0.1, 0.2, 0.1]);
+}
+
+#[test]
+fn test_range_normalization() {
+    let dataset = CsvDataset::parse("-5\n5\n15", false).unwrap();
+    let mut replay =
+        CsvReplay::new(dataset, vec![0], vec![], Normalization::Range(0., 10.)).unwrap();
+
+    let measures: Vec<f32> = (0..3)
+        .map(|_| {
+            replay.next_row().unwrap();
+            replay.get_sensors().get(0)
+        })
+        .collect();
+    assert_eq!(measures, vec![0., 0.5, 1.]);
+}
+
+#[test]
+fn test_replay_errors() {
+    let dataset = CsvDataset::parse("0.5,1\n2,0", false).unwrap();
+
+    match CsvReplay::new(dataset.clone(), vec![0], vec![2], Normalization::None) {
+        Err(CsvError::MissingColumn { column, count }) => assert_eq!((column, count), (2, 2)),
+        _ => panic!("There's no third column"),
+    }
+
+    match CsvReplay::new(dataset.clone(), vec![0], vec![], Normalization::None) {
+        Err(CsvError::OutOfBounds { column, value }) => {
+            assert_eq!(column, 0);
+            cmp_f32(value, 2.);
+        }
+        _ => panic!("2 isn't a measure"),
+    }
+
+    // The second column is already made of measures
+    assert!(CsvReplay::new(dataset.clone(), vec![1], vec![0], Normalization::None).is_ok());
+
+    match CsvReplay::new(dataset, vec![0], vec![], Normalization::Range(1., 1.)) {
+        Err(CsvError::EmptyRange { min, max }) => assert_eq!((min, max), (1., 1.)),
+        _ => panic!("[1, 1] is empty"),
+    }
+}
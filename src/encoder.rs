@@ -0,0 +1,136 @@
+use crate::neuron::{NeuronicInput, NeuronicSensor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// How a scalar is spread over a bank of sensors
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Encoding {
+    /// The range is split into one bin per sensor, and only the
+    /// sensor of the bin the value falls in is set to 1
+    OneHot,
+    /// Like OneHot, but every sensor up to and including
+    /// the value's bin is set to 1
+    Thermometer,
+    /// Sensors have overlapping Gaussian tuning curves, centered
+    /// evenly across the range from min to max, and each is set to
+    /// exp(-(value - center)^2 / (2 * sigma^2)).  sigma is in the
+    /// units of the value
+    Gaussian { sigma: f32 },
+}
+
+/// Encodes a scalar from any range onto a bank of sensors, so it
+/// can drive neurons, which only see measures in [0, 1].  Values
+/// outside of the range are clamped to it
+pub struct Encoder {
+    min: f32,
+    max: f32,
+    encoding: Encoding,
+    sensors: Vec<Rc<NeuronicSensor>>,
+}
+
+impl Encoder {
+    pub fn new(min: f32, max: f32, channels: usize, encoding: Encoding) -> Encoder {
+        if min >= max {
+            panic!("Encoder range [{}, {}] is empty", min, max);
+        }
+
+        if channels == 0 {
+            panic!("Encoder must have at least one channel");
+        }
+
+        if let Encoding::Gaussian { sigma } = encoding {
+            if sigma <= 0. {
+                panic!("Sigma {} out of bounds!  Must be positive", sigma);
+            }
+        }
+
+        Encoder {
+            min,
+            max,
+            encoding,
+            sensors: (0..channels)
+                .map(|_| Rc::new(NeuronicSensor::new()))
+                .collect(),
+        }
+    }
+
+    pub fn get_range(&self) -> (f32, f32) {
+        (self.min, self.max)
+    }
+
+    pub fn get_encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn get_channel_count(&self) -> usize {
+        self.sensors.len()
+    }
+
+    pub fn get_sensors(&self) -> &[Rc<NeuronicSensor>] {
+        &self.sensors
+    }
+
+    /// The sensors as inputs, for example to pass to a Connector
+    pub fn get_inputs(&self) -> Vec<Rc<dyn NeuronicInput>> {
+        self.sensors
+            .iter()
+            .map(|sensor| Rc::clone(sensor) as Rc<dyn NeuronicInput>)
+            .collect()
+    }
+
+    /// The measure of each sensor for a value, without setting them
+    pub fn get_measures(&self, value: f32) -> Vec<f32> {
+        let channels = self.sensors.len();
+        let value = value.clamp(self.min, self.max);
+        let position = (value - self.min) / (self.max - self.min);
+
+        // The top of the range belongs to the last bin
+        let bin = ((position * channels as f32) as usize).min(channels - 1);
+
+        (0..channels)
+            .map(|channel| match self.encoding {
+                Encoding::OneHot => {
+                    if channel == bin {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+                Encoding::Thermometer => {
+                    if channel <= bin {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                }
+                Encoding::Gaussian { sigma } => {
+                    let distance = value - self.get_center(channel);
+                    (-(distance * distance) / (2.0 * sigma * sigma)).exp()
+                }
+            })
+            .collect()
+    }
+
+    /// Sets every sensor to encode the value
+    pub fn encode(&self, value: f32) {
+        for (sensor, measure) in self.sensors.iter().zip(self.get_measures(value)) {
+            sensor.set_measure(measure);
+        }
+    }
+
+    /// Where the tuning curve of a channel peaks, for Gaussian encoding
+    fn get_center(&self, channel: usize) -> f32 {
+        let channels = self.sensors.len();
+
+        if channels == 1 {
+            return (self.min + self.max) / 2.0;
+        }
+
+        self.min + ((self.max - self.min) * channel as f32 / (channels - 1) as f32)
+    }
+}
+
+#[cfg(test)]
+mod encoder_tests;
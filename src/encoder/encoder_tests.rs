@@ -0,0 +1,78 @@
+use crate::encoder::{Encoder, Encoding};
+use crate::neuron::{ChargeCycle, NeuronicInput};
+use crate::test_utils::cmp_f32;
+
+#[test]
+fn test_one_hot() {
+    let encoder = Encoder::new(-10., 30., 4, Encoding::OneHot);
+
+    assert_eq!(encoder.get_measures(-10.), vec![1., 0., 0., 0.]);
+    assert_eq!(encoder.get_measures(5.), vec![0., 1., 0., 0.]);
+    assert_eq!(encoder.get_measures(10.), vec![0., 0., 1., 0.]);
+    assert_eq!(encoder.get_measures(30.), vec![0., 0., 0., 1.]);
+
+    // Out of range values are clamped
+    assert_eq!(encoder.get_measures(-50.), vec![1., 0., 0., 0.]);
+    assert_eq!(encoder.get_measures(100.), vec![0., 0., 0., 1.]);
+}
+
+#[test]
+fn test_thermometer() {
+    let encoder = Encoder::new(0., 360., 4, Encoding::Thermometer);
+
+    assert_eq!(encoder.get_measures(0.), vec![1., 0., 0., 0.]);
+    assert_eq!(encoder.get_measures(200.), vec![1., 1., 1., 0.]);
+    assert_eq!(encoder.get_measures(360.), vec![1., 1., 1., 1.]);
+}
+
+#[test]
+fn test_gaussian() {
+    let encoder = Encoder::new(0., 4., 5, Encoding::Gaussian { sigma: 1. });
+
+    // Centers are at 0, 1, 2, 3 and 4
+    let measures = encoder.get_measures(2.);
+    cmp_f32(measures[2], 1.);
+    cmp_f32(measures[1], (-0.5f32).exp());
+    cmp_f32(measures[3], measures[1]);
+    cmp_f32(measures[0], (-2f32).exp());
+
+    // Between two centers, both respond equally
+    let measures = encoder.get_measures(2.5);
+    cmp_f32(measures[2], measures[3]);
+    assert!(measures[2] < 1.);
+
+    // A single channel is centered on the range
+    let single = Encoder::new(0., 4., 1, Encoding::Gaussian { sigma: 1. });
+    cmp_f32(single.get_measures(2.)[0], 1.);
+}
+
+#[test]
+fn test_encode() {
+    let encoder = Encoder::new(0., 1., 3, Encoding::OneHot);
+    assert_eq!(encoder.get_channel_count(), 3);
+
+    encoder.encode(0.5);
+
+    let inputs = encoder.get_inputs();
+    let measures: Vec<f32> = inputs
+        .iter()
+        .map(|input| input.get_measure(ChargeCycle::Even))
+        .collect();
+    assert_eq!(measures, vec![0., 1., 0.]);
+
+    encoder.encode(0.9);
+    cmp_f32(encoder.get_sensors()[1].get_measure(ChargeCycle::Odd), 0.);
+    cmp_f32(encoder.get_sensors()[2].get_measure(ChargeCycle::Odd), 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_empty_range() {
+    Encoder::new(1., 1., 3, Encoding::OneHot);
+}
+
+#[test]
+#[should_panic]
+fn test_no_channels() {
+    Encoder::new(0., 1., 0, Encoding::Thermometer);
+}
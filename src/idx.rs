@@ -0,0 +1,351 @@
+//! Reader for the IDX binary format that MNIST and similar datasets
+//! are distributed in.  Files must already be decompressed.  An IDX
+//! file is two zero bytes, a byte for the type of the data, a byte for
+//! the number of dimensions, a big endian u32 for each dimension,
+//! and then the data itself, big endian, in row order
+
+use crate::neuron::NeuronicSensor;
+use crate::spatial::Grid;
+use std::convert::TryInto;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum IdxError {
+    Io(io::Error),
+    /// The file doesn't start with two zero bytes
+    BadMagic,
+    UnsupportedType(u8),
+    /// The file is shorter than its header says it should be
+    Truncated {
+        expected: usize,
+        found: usize,
+    },
+    /// Images must have 3 dimensions and labels 1, and the
+    /// dimensions must not describe more data than fits in memory
+    BadShape {
+        dims: Vec<usize>,
+    },
+    CountMismatch {
+        images: usize,
+        labels: usize,
+    },
+    /// A label that isn't a whole number of at least 0
+    BadLabel {
+        index: usize,
+        label: f32,
+    },
+}
+
+impl fmt::Display for IdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IdxError::Io(error) => write!(f, "Couldn't read IDX file: {}", error),
+            IdxError::BadMagic => write!(f, "Not an IDX file"),
+            IdxError::UnsupportedType(code) => {
+                write!(f, "Unsupported IDX data type 0x{:02X}", code)
+            }
+            IdxError::Truncated { expected, found } => write!(
+                f,
+                "IDX file should have {} bytes, but has {}",
+                expected, found
+            ),
+            IdxError::BadShape { dims } => write!(f, "Unexpected IDX shape {:?}", dims),
+            IdxError::CountMismatch { images, labels } => {
+                write!(f, "{} images but {} labels", images, labels)
+            }
+            IdxError::BadLabel { index, label } => {
+                write!(f, "Label {} of item {} isn't a class", label, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdxError {}
+
+impl From<io::Error> for IdxError {
+    fn from(error: io::Error) -> IdxError {
+        IdxError::Io(error)
+    }
+}
+
+/// The type of the values in an IDX file
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IdxType {
+    U8,
+    I8,
+    I16,
+    I32,
+    F32,
+    F64,
+}
+
+impl IdxType {
+    fn from_code(code: u8) -> Result<IdxType, IdxError> {
+        match code {
+            0x08 => Ok(IdxType::U8),
+            0x09 => Ok(IdxType::I8),
+            0x0B => Ok(IdxType::I16),
+            0x0C => Ok(IdxType::I32),
+            0x0D => Ok(IdxType::F32),
+            0x0E => Ok(IdxType::F64),
+            _ => Err(IdxError::UnsupportedType(code)),
+        }
+    }
+
+    fn get_size(&self) -> usize {
+        match self {
+            IdxType::U8 | IdxType::I8 => 1,
+            IdxType::I16 => 2,
+            IdxType::I32 | IdxType::F32 => 4,
+            IdxType::F64 => 8,
+        }
+    }
+
+    fn read(&self, bytes: &[u8]) -> f32 {
+        match self {
+            IdxType::U8 => bytes[0] as f32,
+            IdxType::I8 => bytes[0] as i8 as f32,
+            IdxType::I16 => i16::from_be_bytes(bytes.try_into().unwrap()) as f32,
+            IdxType::I32 => i32::from_be_bytes(bytes.try_into().unwrap()) as f32,
+            IdxType::F32 => f32::from_be_bytes(bytes.try_into().unwrap()),
+            IdxType::F64 => f64::from_be_bytes(bytes.try_into().unwrap()) as f32,
+        }
+    }
+}
+
+/// The contents of an IDX file, with every value converted to f32
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdxArray {
+    data_type: IdxType,
+    dims: Vec<usize>,
+    data: Vec<f32>,
+}
+
+impl IdxArray {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<IdxArray, IdxError> {
+        IdxArray::parse(&fs::read(path)?)
+    }
+
+    pub fn parse(bytes: &[u8]) -> Result<IdxArray, IdxError> {
+        if bytes.len() < 4 {
+            return Err(IdxError::Truncated {
+                expected: 4,
+                found: bytes.len(),
+            });
+        }
+
+        if bytes[0] != 0 || bytes[1] != 0 {
+            return Err(IdxError::BadMagic);
+        }
+
+        let data_type = IdxType::from_code(bytes[2])?;
+        let dim_count = bytes[3] as usize;
+        let header_length = 4 + (4 * dim_count);
+
+        if bytes.len() < header_length {
+            return Err(IdxError::Truncated {
+                expected: header_length,
+                found: bytes.len(),
+            });
+        }
+
+        let dims: Vec<usize> = bytes[4..header_length]
+            .chunks_exact(4)
+            .map(|dim| u32::from_be_bytes(dim.try_into().unwrap()) as usize)
+            .collect();
+
+        let size = data_type.get_size();
+
+        // A malformed header can claim more data than fits in a usize
+        let expected = match dims
+            .iter()
+            .try_fold(size, |total, dim| total.checked_mul(*dim))
+            .and_then(|data_length| data_length.checked_add(header_length))
+        {
+            Some(expected) => expected,
+            None => return Err(IdxError::BadShape { dims }),
+        };
+
+        if bytes.len() < expected {
+            return Err(IdxError::Truncated {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let data = bytes[header_length..expected]
+            .chunks_exact(size)
+            .map(|value| data_type.read(value))
+            .collect();
+
+        Ok(IdxArray {
+            data_type,
+            dims,
+            data,
+        })
+    }
+
+    pub fn get_data_type(&self) -> IdxType {
+        self.data_type
+    }
+
+    pub fn get_dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    pub fn get_data(&self) -> &[f32] {
+        &self.data
+    }
+
+    /// Size of the first dimension
+    pub fn get_item_count(&self) -> usize {
+        self.dims.first().copied().unwrap_or(0)
+    }
+
+    /// The values of one entry of the first dimension,
+    /// for example one image
+    pub fn get_item(&self, index: usize) -> &[f32] {
+        if index >= self.get_item_count() {
+            panic!(
+                "No item at index {}, IDX array has {} items",
+                index,
+                self.get_item_count()
+            );
+        }
+
+        let item_size: usize = self.dims[1..].iter().product();
+        &self.data[(index * item_size)..((index + 1) * item_size)]
+    }
+}
+
+/// Images and their labels, like the MNIST training or test set
+pub struct IdxDataset {
+    images: IdxArray,
+    labels: IdxArray,
+    max_intensity: f32,
+}
+
+impl IdxDataset {
+    pub fn from_paths<P: AsRef<Path>, Q: AsRef<Path>>(
+        images: P,
+        labels: Q,
+    ) -> Result<IdxDataset, IdxError> {
+        IdxDataset::new(IdxArray::from_path(images)?, IdxArray::from_path(labels)?)
+    }
+
+    /// images must be count x height x width, and labels must be
+    /// count whole numbers of at least 0
+    pub fn new(images: IdxArray, labels: IdxArray) -> Result<IdxDataset, IdxError> {
+        if images.dims.len() != 3 {
+            return Err(IdxError::BadShape { dims: images.dims });
+        }
+
+        if labels.dims.len() != 1 {
+            return Err(IdxError::BadShape { dims: labels.dims });
+        }
+
+        if images.get_item_count() != labels.get_item_count() {
+            return Err(IdxError::CountMismatch {
+                images: images.get_item_count(),
+                labels: labels.get_item_count(),
+            });
+        }
+
+        if let Some((index, label)) = labels
+            .data
+            .iter()
+            .enumerate()
+            .find(|(_, label)| !label.is_finite() || **label < 0.0 || label.fract() != 0.0)
+        {
+            return Err(IdxError::BadLabel {
+                index,
+                label: *label,
+            });
+        }
+
+        // Bytes are intensities out of 255, anything else is scaled
+        // by the brightest pixel.  Pixels that aren't finite are dark
+        let max_intensity = match images.data_type {
+            IdxType::U8 => 255.0,
+            _ => images
+                .data
+                .iter()
+                .copied()
+                .filter(|pixel| pixel.is_finite())
+                .fold(0.0, f32::max),
+        };
+
+        Ok(IdxDataset {
+            images,
+            labels,
+            max_intensity,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.images.get_item_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.images.dims[2]
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.images.dims[1]
+    }
+
+    /// Raw pixel values of an image, in row order
+    pub fn get_image(&self, index: usize) -> &[f32] {
+        self.images.get_item(index)
+    }
+
+    pub fn get_label(&self, index: usize) -> usize {
+        self.labels.get_item(index)[0] as usize
+    }
+
+    /// Pixels of an image scaled to measures in [0, 1], in row order,
+    /// for example to pass to SensorArray::set_all.  Pixels that
+    /// aren't finite are 0
+    pub fn get_measures(&self, index: usize) -> Vec<f32> {
+        let max_intensity = self.max_intensity;
+
+        self.get_image(index)
+            .iter()
+            .map(|pixel| {
+                if max_intensity > 0.0 && pixel.is_finite() {
+                    (pixel / max_intensity).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Sets a grid of sensors, the same size as the images,
+    /// to the measures of an image
+    pub fn present(&self, index: usize, sensors: &Grid<NeuronicSensor>) {
+        if sensors.get_width() != self.get_width() || sensors.get_height() != self.get_height() {
+            panic!(
+                "Can't present {}x{} images on a {}x{} sensor grid",
+                self.get_width(),
+                self.get_height(),
+                sensors.get_width(),
+                sensors.get_height()
+            );
+        }
+
+        for (sensor, measure) in sensors.get_items().iter().zip(self.get_measures(index)) {
+            sensor.set_measure(measure);
+        }
+    }
+}
+
+#[cfg(test)]
+mod idx_tests;
@@ -0,0 +1,240 @@
+use crate::idx::{IdxArray, IdxDataset, IdxError, IdxType};
+use crate::neuron::{ChargeCycle, NeuronicInput, NeuronicSensor};
+use crate::spatial::Grid;
+use crate::test_utils::cmp_f32;
+use std::fs;
+use std::rc::Rc;
+
+/// Bytes of an IDX file of unsigned bytes
+fn idx_bytes(dims: &[u32], data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0, 0, 0x08, dims.len() as u8];
+
+    for dim in dims {
+        bytes.extend_from_slice(&dim.to_be_bytes());
+    }
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Bytes of an IDX file of f32s
+fn f32_idx_bytes(dims: &[u32], data: &[f32]) -> Vec<u8> {
+    let mut bytes = vec![0, 0, 0x0D, dims.len() as u8];
+
+    for dim in dims {
+        bytes.extend_from_slice(&dim.to_be_bytes());
+    }
+    for value in data {
+        bytes.extend_from_slice(&value.to_be_bytes());
+    }
+    bytes
+}
+
+/// Two 3x2 images, and their labels
+fn digits() -> (Vec<u8>, Vec<u8>) {
+    (
+        idx_bytes(
+            &[2, 2, 3],
+            &[0, 255, 0, 0, 255, 0, 255, 255, 255, 51, 0, 102],
+        ),
+        idx_bytes(&[2], &[1, 7]),
+    )
+}
+
+#[test]
+fn test_parse() {
+    let (images, _) = digits();
+    let array = IdxArray::parse(&images).unwrap();
+
+    assert_eq!(array.get_data_type(), IdxType::U8);
+    assert_eq!(array.get_dims(), &[2, 2, 3]);
+    assert_eq!(array.get_item_count(), 2);
+    assert_eq!(array.get_item(1), &[255., 255., 255., 51., 0., 102.]);
+}
+
+#[test]
+fn test_parse_wider_types() {
+    let mut bytes = vec![0, 0, 0x0B, 1];
+    bytes.extend_from_slice(&2u32.to_be_bytes());
+    bytes.extend_from_slice(&(-300i16).to_be_bytes());
+    bytes.extend_from_slice(&1000i16.to_be_bytes());
+
+    let array = IdxArray::parse(&bytes).unwrap();
+    assert_eq!(array.get_data_type(), IdxType::I16);
+    assert_eq!(array.get_data(), &[-300., 1000.]);
+
+    let mut bytes = vec![0, 0, 0x0D, 1];
+    bytes.extend_from_slice(&1u32.to_be_bytes());
+    bytes.extend_from_slice(&0.25f32.to_be_bytes());
+    assert_eq!(IdxArray::parse(&bytes).unwrap().get_data(), &[0.25]);
+}
+
+#[test]
+fn test_parse_errors() {
+    match IdxArray::parse(&[1, 0, 0x08, 1]) {
+        Err(IdxError::BadMagic) => (),
+        _ => panic!("Magic number is wrong"),
+    }
+
+    match IdxArray::parse(&[0, 0, 0x01, 1]) {
+        Err(IdxError::UnsupportedType(0x01)) => (),
+        _ => panic!("0x01 isn't a type"),
+    }
+
+    let mut truncated = idx_bytes(&[4], &[1, 2, 3, 4]);
+    truncated.pop();
+    match IdxArray::parse(&truncated) {
+        Err(IdxError::Truncated { expected, found }) => assert_eq!((expected, found), (12, 11)),
+        _ => panic!("Data is missing a byte"),
+    }
+
+    match IdxArray::parse(&idx_bytes(&[u32::MAX; 4], &[])) {
+        Err(IdxError::BadShape { dims }) => assert_eq!(dims, vec![u32::MAX as usize; 4]),
+        _ => panic!("Header claims more data than can be addressed"),
+    }
+
+    match IdxArray::from_path("/this/file/does/not/exist-idx3-ubyte") {
+        Err(IdxError::Io(_)) => (),
+        _ => panic!("File doesn't exist"),
+    }
+}
+
+#[test]
+fn test_dataset() {
+    let (images, labels) = digits();
+    let dataset = IdxDataset::new(
+        IdxArray::parse(&images).unwrap(),
+        IdxArray::parse(&labels).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(dataset.len(), 2);
+    assert_eq!((dataset.get_width(), dataset.get_height()), (3, 2));
+    assert_eq!(dataset.get_label(0), 1);
+    assert_eq!(dataset.get_label(1), 7);
+
+    let measures = dataset.get_measures(1);
+    cmp_f32(measures[0], 1.);
+    cmp_f32(measures[3], 0.2);
+    cmp_f32(measures[4], 0.);
+    cmp_f32(measures[5], 0.4);
+}
+
+#[test]
+fn test_dataset_errors() {
+    let (images, labels) = digits();
+    let images = IdxArray::parse(&images).unwrap();
+    let labels = IdxArray::parse(&labels).unwrap();
+
+    match IdxDataset::new(labels.clone(), labels.clone()) {
+        Err(IdxError::BadShape { dims }) => assert_eq!(dims, vec![2]),
+        _ => panic!("Labels aren't images"),
+    }
+
+    let three_labels = IdxArray::parse(&idx_bytes(&[3], &[1, 2, 3])).unwrap();
+    match IdxDataset::new(images.clone(), three_labels) {
+        Err(IdxError::CountMismatch { images, labels }) => assert_eq!((images, labels), (2, 3)),
+        _ => panic!("Counts don't match"),
+    }
+
+    let mut negative_labels = vec![0, 0, 0x09, 1];
+    negative_labels.extend_from_slice(&2u32.to_be_bytes());
+    negative_labels.extend_from_slice(&[1, -3i8 as u8]);
+    match IdxDataset::new(images.clone(), IdxArray::parse(&negative_labels).unwrap()) {
+        Err(IdxError::BadLabel { index, label }) => assert_eq!((index, label), (1, -3.)),
+        _ => panic!("-3 isn't a class"),
+    }
+
+    let fractional_labels = IdxArray::parse(&f32_idx_bytes(&[2], &[1.5, 2.])).unwrap();
+    match IdxDataset::new(images.clone(), fractional_labels) {
+        Err(IdxError::BadLabel { index, label }) => assert_eq!((index, label), (0, 1.5)),
+        _ => panic!("1.5 isn't a class"),
+    }
+
+    let nan_labels = IdxArray::parse(&f32_idx_bytes(&[2], &[1., f32::NAN])).unwrap();
+    match IdxDataset::new(images, nan_labels) {
+        Err(IdxError::BadLabel { index, .. }) => assert_eq!(index, 1),
+        _ => panic!("NaN isn't a class"),
+    }
+}
+
+#[test]
+fn test_non_finite_pixels() {
+    let images = f32_idx_bytes(&[1, 1, 4], &[f32::NAN, f32::INFINITY, 2., 1.]);
+    let labels = f32_idx_bytes(&[1], &[3.]);
+    let dataset = IdxDataset::new(
+        IdxArray::parse(&images).unwrap(),
+        IdxArray::parse(&labels).unwrap(),
+    )
+    .unwrap();
+
+    // Pixels that aren't finite are dark, and don't set the brightest pixel
+    assert_eq!(dataset.get_measures(0), vec![0., 0., 1., 0.5]);
+    assert_eq!(dataset.get_label(0), 3);
+
+    let sensors = Grid::from_fn(4, 1, |_, _| Rc::new(NeuronicSensor::new()));
+    dataset.present(0, &sensors);
+    cmp_f32(sensors.get(3, 0).get_measure(ChargeCycle::Even), 0.5);
+}
+
+#[test]
+fn test_from_paths() {
+    let (images, labels) = digits();
+    let dir = std::env::temp_dir();
+    let image_path = dir.join(format!("aesir3_images_{}.idx", std::process::id()));
+    let label_path = dir.join(format!("aesir3_labels_{}.idx", std::process::id()));
+
+    fs::write(&image_path, images).unwrap();
+    fs::write(&label_path, labels).unwrap();
+
+    let dataset = IdxDataset::from_paths(&image_path, &label_path);
+
+    fs::remove_file(&image_path).unwrap();
+    fs::remove_file(&label_path).unwrap();
+
+    let dataset = dataset.unwrap();
+    assert_eq!(dataset.len(), 2);
+    assert_eq!(dataset.get_label(1), 7);
+}
+
+#[test]
+fn test_present() {
+    let (images, labels) = digits();
+    let dataset = IdxDataset::new(
+        IdxArray::parse(&images).unwrap(),
+        IdxArray::parse(&labels).unwrap(),
+    )
+    .unwrap();
+
+    let sensors = Grid::from_fn(3, 2, |_, _| Rc::new(NeuronicSensor::new()));
+
+    // The first image is a vertical bar down the middle
+    dataset.present(0, &sensors);
+
+    for (index, sensor) in sensors.get_items().iter().enumerate() {
+        let expected = if sensors.get_position(index).0 == 1 {
+            1.
+        } else {
+            0.
+        };
+        cmp_f32(sensor.get_measure(ChargeCycle::Even), expected);
+    }
+
+    dataset.present(1, &sensors);
+    cmp_f32(sensors.get(2, 1).get_measure(ChargeCycle::Even), 0.4);
+}
+
+#[test]
+#[should_panic]
+fn test_present_wrong_grid() {
+    let (images, labels) = digits();
+    let dataset = IdxDataset::new(
+        IdxArray::parse(&images).unwrap(),
+        IdxArray::parse(&labels).unwrap(),
+    )
+    .unwrap();
+
+    dataset.present(
+        0,
+        &Grid::from_fn(2, 3, |_, _| Rc::new(NeuronicSensor::new())),
+    );
+}
@@ -0,0 +1,134 @@
+use crate::neuron::SynapticType;
+use crate::weight_bounds::WeightBounds;
+use std::cell::RefCell;
+
+/// A set of weights shared by the synapses of many neurons, like the
+/// kernel of a convolution, so one feature detector can be replicated
+/// across a grid.  Synapses created with Neuron::create_shared_synapse
+/// read their weight from the kernel, and instead of changing it when
+/// they learn, add their update to the kernel.  apply_updates then
+/// moves each weight by the mean of the updates it got, which a Network
+/// does after every learning cycle for the kernels added to it
+pub struct SharedKernel {
+    weights: RefCell<Vec<f32>>,
+    synaptic_type: SynapticType,
+    weight_bounds: WeightBounds,
+    pending: RefCell<Vec<(f32, u32)>>,
+}
+
+impl SharedKernel {
+    /// Every synapse sharing the kernel has the given type, and
+    /// weights are kept within weight_bounds when updates are applied
+    pub fn new(
+        weights: Vec<f32>,
+        synaptic_type: SynapticType,
+        weight_bounds: WeightBounds,
+    ) -> SharedKernel {
+        let pending = vec![(0.0, 0); weights.len()];
+
+        SharedKernel {
+            weights: RefCell::new(weights),
+            synaptic_type,
+            weight_bounds,
+            pending: RefCell::new(pending),
+        }
+    }
+
+    pub fn get_synaptic_type(&self) -> SynapticType {
+        self.synaptic_type
+    }
+
+    pub fn get_weight_bounds(&self) -> WeightBounds {
+        self.weight_bounds
+    }
+
+    pub fn len(&self) -> usize {
+        self.weights.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.borrow().is_empty()
+    }
+
+    pub fn get_weight(&self, index: usize) -> f32 {
+        self.check_index(index);
+        self.weights.borrow()[index]
+    }
+
+    pub fn set_weight(&self, index: usize, weight: f32) {
+        self.check_index(index);
+        self.weights.borrow_mut()[index] = weight;
+    }
+
+    pub fn get_weights(&self) -> Vec<f32> {
+        self.weights.borrow().clone()
+    }
+
+    /// Overwrites every weight, and drops any updates not yet applied
+    pub fn set_weights(&self, weights: &[f32]) {
+        if weights.len() != self.len() {
+            panic!(
+                "Got {} weights for a kernel of size {}",
+                weights.len(),
+                self.len()
+            );
+        }
+
+        self.weights.borrow_mut().copy_from_slice(weights);
+        self.clear_updates();
+    }
+
+    /// Records the update one sharing synapse wants to make to a weight
+    pub fn add_update(&self, index: usize, delta: f32) {
+        self.check_index(index);
+
+        let mut pending = self.pending.borrow_mut();
+        pending[index].0 += delta;
+        pending[index].1 += 1;
+    }
+
+    /// Number of updates recorded for each weight since they were last applied
+    pub fn get_update_counts(&self) -> Vec<u32> {
+        self.pending
+            .borrow()
+            .iter()
+            .map(|(_, count)| *count)
+            .collect()
+    }
+
+    /// Moves each weight by the mean of its recorded updates,
+    /// so how fast the kernel learns doesn't depend on how many
+    /// neurons share it, then forgets the updates
+    pub fn apply_updates(&self) {
+        let mut weights = self.weights.borrow_mut();
+        let mut pending = self.pending.borrow_mut();
+
+        for (weight, (delta, count)) in weights.iter_mut().zip(pending.iter_mut()) {
+            if *count > 0 {
+                *weight =
+                    self.weight_bounds
+                        .apply(self.synaptic_type, *weight, *delta / *count as f32);
+            }
+
+            *delta = 0.0;
+            *count = 0;
+        }
+    }
+
+    pub fn clear_updates(&self) {
+        for update in self.pending.borrow_mut().iter_mut() {
+            *update = (0.0, 0);
+        }
+    }
+
+    fn check_index(&self, index: usize) {
+        let len = self.len();
+
+        if index >= len {
+            panic!("No weight at index {}, kernel has {} weights", index, len);
+        }
+    }
+}
+
+#[cfg(test)]
+mod kernel_tests;
@@ -0,0 +1,120 @@
+use crate::kernel::SharedKernel;
+use crate::network::Network;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::test_utils::cmp_f32;
+use crate::weight_bounds::{ClipPolicy, WeightBounds};
+use std::rc::Rc;
+
+fn kernel(weights: Vec<f32>) -> Rc<SharedKernel> {
+    Rc::new(SharedKernel::new(
+        weights,
+        SynapticType::Excitatory,
+        WeightBounds::new(0., 8., ClipPolicy::Hard),
+    ))
+}
+
+#[test]
+fn test_kernel_updates() {
+    let kernel = kernel(vec![1., 2., 7.]);
+
+    assert_eq!(kernel.len(), 3);
+    assert_eq!(kernel.get_weights(), vec![1., 2., 7.]);
+
+    kernel.add_update(0, 1.);
+    kernel.add_update(0, 0.5);
+    kernel.add_update(2, 3.);
+    assert_eq!(kernel.get_update_counts(), vec![2, 0, 1]);
+
+    // Nothing changes until the updates are applied
+    assert_eq!(kernel.get_weights(), vec![1., 2., 7.]);
+
+    // Updates are averaged, and the bounds still apply
+    kernel.apply_updates();
+    cmp_f32(kernel.get_weight(0), 1.75);
+    cmp_f32(kernel.get_weight(1), 2.);
+    cmp_f32(kernel.get_weight(2), 8.);
+    assert_eq!(kernel.get_update_counts(), vec![0, 0, 0]);
+
+    kernel.add_update(1, 1.);
+    kernel.clear_updates();
+    kernel.apply_updates();
+    cmp_f32(kernel.get_weight(1), 2.);
+}
+
+#[test]
+#[should_panic]
+fn test_kernel_index_out_of_bounds() {
+    kernel(vec![1.]).add_update(1, 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_kernel_size_mismatch() {
+    kernel(vec![1., 2.]).set_weights(&[1.]);
+}
+
+#[test]
+fn test_shared_synapses() {
+    let kernel = kernel(vec![4., 4.]);
+
+    let sensors: Vec<Rc<NeuronicSensor>> = (0..3).map(|_| Rc::new(NeuronicSensor::new())).collect();
+    let n1 = Neuron::new(1., 8., 0.1);
+    let n2 = Neuron::new(1., 8., 0.1);
+
+    // Both neurons use the same kernel on different inputs
+    n1.create_shared_synapse(&kernel, 0, Rc::clone(&sensors[0]) as Rc<dyn NeuronicInput>);
+    n1.create_shared_synapse(&kernel, 1, Rc::clone(&sensors[1]) as Rc<dyn NeuronicInput>);
+    n2.create_shared_synapse(&kernel, 0, Rc::clone(&sensors[1]) as Rc<dyn NeuronicInput>);
+    n2.create_shared_synapse(&kernel, 1, Rc::clone(&sensors[2]) as Rc<dyn NeuronicInput>);
+
+    assert_eq!(n1.get_synaptic_types(), vec![SynapticType::Excitatory; 2]);
+
+    // Changing the weight through one neuron changes it for the other
+    n1.set_weights(&[2., 3.]);
+    assert_eq!(n2.get_weights(), vec![2., 3.]);
+    assert_eq!(kernel.get_weights(), vec![2., 3.]);
+
+    sensors[0].set_measure(0.5);
+    sensors[1].set_measure(0.5);
+    sensors[2].set_measure(0.5);
+
+    n1.run_cycle(ChargeCycle::Even);
+    n2.run_cycle(ChargeCycle::Even);
+
+    // Learning is collected rather than applied
+    assert_eq!(kernel.get_update_counts(), vec![2, 2]);
+    assert_eq!(n1.get_weights(), vec![2., 3.]);
+
+    kernel.apply_updates();
+
+    // Every synapse fired at the measure of the neuron
+    let delta_0 = 0.1 * (8. - 2.) * 0.5;
+    let delta_1 = 0.1 * (8. - 3.) * 0.5;
+    cmp_f32(n1.get_weights()[0], 2. + delta_0);
+    cmp_f32(n2.get_weights()[1], 3. + delta_1);
+}
+
+#[test]
+fn test_network_applies_kernels() {
+    let kernel = kernel(vec![4.]);
+    let sensor = Rc::new(NeuronicSensor::new());
+    sensor.set_measure(0.5);
+
+    let mut network = Network::new();
+
+    for _ in 0..3 {
+        let neuron = Rc::new(Neuron::new(1., 8., 0.1));
+        neuron.create_shared_synapse(&kernel, 0, Rc::clone(&sensor) as Rc<dyn NeuronicInput>);
+        network.add_neuron(neuron as Rc<dyn Neuronic>);
+    }
+    network.add_kernel(Rc::clone(&kernel));
+
+    network.run_static_cycle();
+    assert_eq!(kernel.get_update_counts(), vec![0]);
+    cmp_f32(kernel.get_weight(0), 4.);
+
+    // Three identical updates average to one
+    network.run_cycle();
+    assert_eq!(kernel.get_update_counts(), vec![0]);
+    cmp_f32(kernel.get_weight(0), 4. + (0.1 * (8. - 4.) * 0.5));
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Anneals a Neuron's learning_constant over time, where t is
+/// the tick of the clock the schedule is attached to
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LearningSchedule {
+    /// learning_constant, forever
+    Constant,
+    /// Multiplies the rate by factor every `every` ticks
+    Step { every: u64, factor: f32 },
+    /// learning_constant * decay^t
+    Exponential { decay: f32 },
+    /// learning_constant / (1 + decay * t)
+    InverseTime { decay: f32 },
+}
+
+impl LearningSchedule {
+    pub fn get_rate(&self, learning_constant: f32, tick: u64) -> f32 {
+        match *self {
+            LearningSchedule::Constant => learning_constant,
+            LearningSchedule::Step { every, factor } => {
+                if every == 0 {
+                    return learning_constant;
+                }
+                learning_constant * factor.powi((tick / every) as i32)
+            }
+            LearningSchedule::Exponential { decay } => learning_constant * decay.powf(tick as f32),
+            LearningSchedule::InverseTime { decay } => {
+                learning_constant / (1.0 + (decay * tick as f32))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod learning_schedule_tests;
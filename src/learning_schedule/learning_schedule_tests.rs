@@ -0,0 +1,135 @@
+use crate::learning_schedule::LearningSchedule;
+use crate::network::Network;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::test_utils::cmp_f32;
+use std::rc::Rc;
+
+#[test]
+fn test_schedule_rates() {
+    let constant = LearningSchedule::Constant;
+    cmp_f32(constant.get_rate(0.5, 0), 0.5);
+    cmp_f32(constant.get_rate(0.5, 1000), 0.5);
+
+    let step = LearningSchedule::Step {
+        every: 10,
+        factor: 0.5,
+    };
+    cmp_f32(step.get_rate(0.8, 0), 0.8);
+    cmp_f32(step.get_rate(0.8, 9), 0.8);
+    cmp_f32(step.get_rate(0.8, 10), 0.4);
+    cmp_f32(step.get_rate(0.8, 25), 0.2);
+
+    let exponential = LearningSchedule::Exponential { decay: 0.9 };
+    cmp_f32(exponential.get_rate(0.8, 0), 0.8);
+    cmp_f32(exponential.get_rate(0.8, 2), 0.8 * 0.9_f32.powf(2.));
+
+    let inverse = LearningSchedule::InverseTime { decay: 0.5 };
+    cmp_f32(inverse.get_rate(0.8, 0), 0.8);
+    cmp_f32(inverse.get_rate(0.8, 6), 0.2);
+}
+
+#[test]
+fn test_neuron_schedule_follows_network_clock() {
+    let mut network = Network::new();
+
+    let neuron = Rc::new(Neuron::new(1., 8., 0.8).with_learning_schedule(
+        LearningSchedule::Step {
+            every: 2,
+            factor: 0.5,
+        },
+        network.get_clock(),
+    ));
+    network.add_neuron(Rc::clone(&neuron) as Rc<dyn Neuronic>);
+
+    cmp_f32(neuron.get_learning_rate(), 0.8);
+
+    network.run_static_cycle();
+    cmp_f32(neuron.get_learning_rate(), 0.8);
+
+    network.run_cycle();
+    cmp_f32(neuron.get_learning_rate(), 0.4);
+
+    // Clearing the network doesn't restart the schedule
+    network.clear();
+    cmp_f32(neuron.get_learning_rate(), 0.4);
+}
+
+#[test]
+fn test_synapse_learning_rates() {
+    let neuron = Neuron::new(1., 8., 0.1);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    let s3 = Rc::new(NeuronicSensor::new());
+
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s3) as Rc<dyn NeuronicInput>,
+    );
+
+    neuron.set_synapse_learning_rate(1, 2.);
+    neuron.set_synapse_frozen(2, true);
+
+    assert_eq!(neuron.get_synapse_learning_rates(), vec![1., 2., 1.]);
+    assert!(!neuron.is_synapse_frozen(0));
+    assert!(neuron.is_synapse_frozen(2));
+
+    s1.set_measure(0.5);
+    s2.set_measure(0.5);
+    s3.set_measure(0.5);
+
+    neuron.run_cycle(ChargeCycle::Even);
+
+    let delta = 0.1 * (8. - 4.) * 0.5;
+    let weights = neuron.get_weights();
+    cmp_f32(weights[0], 4. + delta);
+    cmp_f32(weights[1], 4. + (2. * delta));
+    cmp_f32(weights[2], 4.);
+}
+
+#[test]
+fn test_frozen_neuron() {
+    let neuron = Neuron::new(1., 8., 0.1);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.5);
+
+    neuron.set_frozen(true);
+    assert!(neuron.is_frozen());
+
+    // A frozen neuron still fires, it just doesn't learn
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.5);
+    cmp_f32(neuron.get_weights()[0], 4.);
+
+    neuron.set_frozen(false);
+    neuron.run_cycle(ChargeCycle::Odd);
+    cmp_f32(neuron.get_weights()[0], 4. + (0.1 * (8. - 4.) * 0.5));
+}
+
+#[test]
+#[should_panic]
+fn test_missing_synapse() {
+    Neuron::new(1., 8., 0.1).set_synapse_frozen(0, true);
+}
+
+#[test]
+#[should_panic]
+fn test_missing_synapse_frozen() {
+    Neuron::new(1., 8., 0.1).is_synapse_frozen(0);
+}
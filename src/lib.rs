@@ -1,2 +1,50 @@
+pub mod clock;
+pub mod connectivity;
+pub mod csv_replay;
+pub mod encoder;
+pub mod idx;
+pub mod kernel;
+pub mod learning_schedule;
+pub mod network;
+pub mod neuromodulator;
 pub mod neuron;
-pub use neuron::{Neuron, NeuronicSensor};
+pub mod neuron_types;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod rng;
+pub mod sensor_array;
+pub mod short_term_plasticity;
+pub mod spatial;
+pub mod supervised;
+#[cfg(test)]
+mod test_utils;
+pub mod timed_source;
+pub mod weight_bounds;
+pub mod weight_init;
+pub mod weight_normalization;
+pub mod wta;
+
+pub use clock::Clock;
+pub use connectivity::{Connector, Pattern};
+pub use csv_replay::{CsvDataset, CsvError, CsvReplay, Normalization};
+pub use encoder::{Encoder, Encoding};
+pub use idx::{IdxArray, IdxDataset, IdxError, IdxType};
+pub use kernel::SharedKernel;
+pub use learning_schedule::LearningSchedule;
+pub use network::{Network, NetworkError, SourceSign};
+pub use neuromodulator::Neuromodulator;
+pub use neuron::{
+    Homeostasis, InhibitoryRule, Neuron, NeuronState, NeuronicSensor, Refractoriness,
+    RefractoryMode, SomaRule, TeacherMode,
+};
+pub use neuron_types::{BiasNeuron, LinearNeuron, MaxNeuron, MinNeuron, RelayNeuron};
+pub use rng::Rng;
+pub use sensor_array::{SensorArray, SensorChannel};
+pub use short_term_plasticity::ShortTermPlasticity;
+pub use spatial::{DistanceConnector, Falloff, Grid, ReceptiveField};
+pub use supervised::Supervisor;
+pub use timed_source::TimedSource;
+pub use weight_bounds::{ClipPolicy, WeightBounds};
+pub use weight_init::WeightInit;
+pub use weight_normalization::{Norm, WeightNormalization};
+pub use wta::WtaLayer;
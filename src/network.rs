@@ -0,0 +1,280 @@
+use crate::clock::Clock;
+use crate::kernel::SharedKernel;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, SynapticType};
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// Whether a presynaptic source may drive excitatory
+/// or inhibitory synapses under Dale's law
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SourceSign {
+    Excitatory,
+    Inhibitory,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NetworkError {
+    /// Dale's law is enforced, but the source was never declared
+    UndeclaredSource,
+    /// The synaptic type doesn't match the declared sign of the source
+    DalesLawViolation {
+        source_sign: SourceSign,
+        synaptic_type: SynapticType,
+    },
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetworkError::UndeclaredSource => {
+                write!(f, "Source must be declared excitatory or inhibitory")
+            }
+            NetworkError::DalesLawViolation {
+                source_sign,
+                synaptic_type,
+            } => write!(
+                f,
+                "{:?} source can't drive a {:?} synapse",
+                source_sign, synaptic_type
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+/// A Network owns the order in which neurons are run and keeps track
+/// of the current ChargeCycle, so that stepping the whole graph is
+/// a single call instead of manually alternating cycles
+pub struct Network {
+    neurons: Vec<Rc<dyn Neuronic>>,
+    cycle: ChargeCycle,
+    clock: Rc<Clock>,
+    dales_law: bool,
+    dales_law_checked: bool,
+    source_signs: HashMap<*const (), (Rc<dyn NeuronicInput>, SourceSign)>,
+    kernels: Vec<Rc<SharedKernel>>,
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Network {
+    pub fn new() -> Network {
+        Network {
+            neurons: Vec::new(),
+            cycle: ChargeCycle::Even,
+            clock: Rc::new(Clock::new()),
+            dales_law: false,
+            dales_law_checked: false,
+            source_signs: HashMap::new(),
+            kernels: Vec::new(),
+        }
+    }
+
+    /// Enforces Dale's law: each source must be declared excitatory or
+    /// inhibitory, and may only drive synapses of its own sign.  Gating
+    /// and modulatory synapses are allowed from either kind of source,
+    /// and shunting synapses count as inhibitory.
+    ///
+    /// connect checks each synapse before creating it.  Synapses
+    /// created any other way, for example by a Connector or a
+    /// ReceptiveField, are checked by check_dales_law.  The network
+    /// runs that check itself before the first step after a neuron is
+    /// added or a source is declared, and panics if it fails.  Synapses
+    /// created some other way after that aren't seen until
+    /// check_dales_law is called again
+    pub fn with_dales_law(mut self) -> Network {
+        self.dales_law = true;
+        self
+    }
+
+    pub fn is_enforcing_dales_law(&self) -> bool {
+        self.dales_law
+    }
+
+    /// Declares the sign of a presynaptic source, replacing any
+    /// earlier declaration.  The network keeps the source alive
+    pub fn declare_source(&mut self, source: Rc<dyn NeuronicInput>, sign: SourceSign) {
+        self.dales_law_checked = false;
+        self.source_signs
+            .insert(source_key(&source), (source, sign));
+    }
+
+    pub fn get_source_sign(&self, source: &Rc<dyn NeuronicInput>) -> Option<SourceSign> {
+        self.source_signs
+            .get(&source_key(source))
+            .map(|(_, sign)| *sign)
+    }
+
+    /// Creates a synapse on the neuron, after checking Dale's law if
+    /// the network enforces it
+    pub fn connect(
+        &self,
+        neuron: &dyn Neuronic,
+        starting_weight: f32,
+        synaptic_type: SynapticType,
+        input: Rc<dyn NeuronicInput>,
+    ) -> Result<(), NetworkError> {
+        if self.dales_law {
+            self.check_synapse(&input, synaptic_type)?;
+        }
+
+        neuron.create_synapse(starting_weight, synaptic_type, input);
+        Ok(())
+    }
+
+    /// Checks every synapse of every neuron in the network against
+    /// Dale's law, if the network enforces it
+    pub fn check_dales_law(&self) -> Result<(), NetworkError> {
+        if !self.dales_law {
+            return Ok(());
+        }
+
+        for neuron in self.neurons.iter() {
+            for (input, synaptic_type) in neuron.get_synapse_inputs() {
+                self.check_synapse(&input, synaptic_type)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_synapse(
+        &self,
+        input: &Rc<dyn NeuronicInput>,
+        synaptic_type: SynapticType,
+    ) -> Result<(), NetworkError> {
+        let source_sign = self
+            .get_source_sign(input)
+            .ok_or(NetworkError::UndeclaredSource)?;
+
+        let allowed = match synaptic_type {
+            SynapticType::Excitatory => source_sign == SourceSign::Excitatory,
+            SynapticType::Inhibitory | SynapticType::Shunting => {
+                source_sign == SourceSign::Inhibitory
+            }
+            SynapticType::Gating | SynapticType::Modulatory => true,
+        };
+
+        if allowed {
+            Ok(())
+        } else {
+            Err(NetworkError::DalesLawViolation {
+                source_sign,
+                synaptic_type,
+            })
+        }
+    }
+
+    /// Checks Dale's law if anything changed since the last check,
+    /// so the synapses aren't walked on every step
+    fn assert_dales_law(&mut self) {
+        if self.dales_law_checked {
+            return;
+        }
+
+        if let Err(error) = self.check_dales_law() {
+            panic!("{}", error);
+        }
+        self.dales_law_checked = true;
+    }
+
+    /// Adds a neuron to the network and declares it an inhibitory
+    /// source, so it can only inhibit the neurons it's connected to
+    pub fn add_inhibitory_interneuron(&mut self, interneuron: Neuron) -> Rc<Neuron> {
+        let interneuron = Rc::new(interneuron);
+
+        self.add_neuron(Rc::clone(&interneuron) as Rc<dyn Neuronic>);
+        self.declare_source(
+            Rc::clone(&interneuron) as Rc<dyn NeuronicInput>,
+            SourceSign::Inhibitory,
+        );
+
+        interneuron
+    }
+
+    /// Adds a neuron to the network.  Neurons are run in the
+    /// order they were added, although because every neuron reads
+    /// its inputs from the previous cycle, the order doesn't change
+    /// the result of a step
+    pub fn add_neuron(&mut self, neuron: Rc<dyn Neuronic>) {
+        self.dales_law_checked = false;
+        self.neurons.push(neuron);
+    }
+
+    /// The kernel's updates are applied after every learning cycle,
+    /// once all of the neurons sharing it have learned
+    pub fn add_kernel(&mut self, kernel: Rc<SharedKernel>) {
+        self.kernels.push(kernel);
+    }
+
+    pub fn get_neuron_count(&self) -> usize {
+        self.neurons.len()
+    }
+
+    /// The cycle that will be used by the next step
+    pub fn get_cycle(&self) -> ChargeCycle {
+        self.cycle
+    }
+
+    /// The clock advances once per step, learning or not
+    pub fn get_clock(&self) -> Rc<Clock> {
+        Rc::clone(&self.clock)
+    }
+
+    /// Runs a learning cycle on every neuron, then advances the cycle
+    pub fn run_cycle(&mut self) {
+        self.assert_dales_law();
+
+        for neuron in self.neurons.iter() {
+            neuron.run_cycle(self.cycle);
+        }
+
+        for kernel in self.kernels.iter() {
+            kernel.apply_updates();
+        }
+
+        self.cycle = self.cycle.next_cycle();
+        self.clock.advance();
+    }
+
+    /// Runs a cycle without learning on every neuron, then advances the cycle
+    pub fn run_static_cycle(&mut self) {
+        self.assert_dales_law();
+
+        for neuron in self.neurons.iter() {
+            neuron.run_static_cycle(self.cycle);
+        }
+
+        self.cycle = self.cycle.next_cycle();
+        self.clock.advance();
+    }
+
+    /// Clears the measure of every neuron, drops kernel
+    /// updates that weren't applied, and resets the cycle.
+    /// The clock keeps running, so schedules aren't restarted
+    pub fn clear(&mut self) {
+        for neuron in self.neurons.iter() {
+            neuron.clear();
+        }
+
+        for kernel in self.kernels.iter() {
+            kernel.clear_updates();
+        }
+
+        self.cycle = ChargeCycle::Even;
+    }
+}
+
+/// Sources are identified by the address they point to
+fn source_key(source: &Rc<dyn NeuronicInput>) -> *const () {
+    Rc::as_ptr(source) as *const ()
+}
+
+#[cfg(test)]
+mod network_tests;
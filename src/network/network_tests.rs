@@ -0,0 +1,400 @@
+use crate::connectivity::{Connector, Pattern};
+use crate::network::{Network, NetworkError, SourceSign};
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::rng::Rng;
+use crate::test_utils::cmp_f32;
+use crate::weight_init::WeightInit;
+use std::rc::Rc;
+
+#[test]
+fn test_cycle_alternates() {
+    let mut network = Network::new();
+
+    match network.get_cycle() {
+        ChargeCycle::Even => (),
+        ChargeCycle::Odd => panic!("Network should start on the even cycle"),
+    }
+
+    network.run_static_cycle();
+
+    match network.get_cycle() {
+        ChargeCycle::Odd => (),
+        ChargeCycle::Even => panic!("Network should have advanced to the odd cycle"),
+    }
+
+    network.run_cycle();
+
+    match network.get_cycle() {
+        ChargeCycle::Even => (),
+        ChargeCycle::Odd => panic!("Network should have advanced to the even cycle"),
+    }
+}
+
+#[test]
+fn test_network_propagation() {
+    let fire_threshold = 10.;
+    let max_weight = 8.;
+    let learning_constant = 0.1;
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+
+    let n1 = Rc::new(Neuron::new(fire_threshold, max_weight, learning_constant));
+    n1.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    n1.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+
+    let n2 = Rc::new(Neuron::new(fire_threshold, max_weight, learning_constant));
+    n2.create_synapse(
+        12.,
+        SynapticType::Excitatory,
+        Rc::clone(&n1) as Rc<dyn NeuronicInput>,
+    );
+
+    let mut network = Network::new();
+
+    // Add the downstream neuron first to show order doesn't matter
+    network.add_neuron(Rc::clone(&n2) as Rc<dyn Neuronic>);
+    network.add_neuron(Rc::clone(&n1) as Rc<dyn Neuronic>);
+
+    assert_eq!(network.get_neuron_count(), 2);
+
+    s1.set_measure(0.9);
+    s2.set_measure(0.6);
+
+    network.run_static_cycle();
+
+    cmp_f32(n1.get_measure(ChargeCycle::Even), 0.6);
+    cmp_f32(n2.get_measure(ChargeCycle::Even), 0.0);
+
+    network.run_static_cycle();
+
+    cmp_f32(n1.get_measure(ChargeCycle::Odd), 0.6);
+    cmp_f32(n2.get_measure(ChargeCycle::Odd), 0.6);
+
+    network.clear();
+
+    cmp_f32(n1.get_measure(ChargeCycle::Even), 0.0);
+    cmp_f32(n1.get_measure(ChargeCycle::Odd), 0.0);
+    cmp_f32(n2.get_measure(ChargeCycle::Even), 0.0);
+    cmp_f32(n2.get_measure(ChargeCycle::Odd), 0.0);
+}
+
+#[test]
+fn test_network_learning() {
+    let s1 = Rc::new(NeuronicSensor::new());
+
+    let n1 = Rc::new(Neuron::new(1., 8., 0.1));
+    n1.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    let mut network = Network::new();
+    network.add_neuron(Rc::clone(&n1) as Rc<dyn Neuronic>);
+
+    s1.set_measure(0.5);
+
+    // Static cycles never touch weights
+    network.run_static_cycle();
+    cmp_f32(n1.get_weights()[0], 4.);
+
+    network.run_cycle();
+    cmp_f32(n1.get_weights()[0], 4. + (0.1 * (8. - 4.) * 0.5));
+}
+
+#[test]
+fn test_clock() {
+    let mut network = Network::new();
+    let clock = network.get_clock();
+
+    assert_eq!(clock.get_tick(), 0);
+
+    network.run_cycle();
+    network.run_static_cycle();
+
+    assert_eq!(clock.get_tick(), 2);
+
+    network.clear();
+
+    assert_eq!(clock.get_tick(), 2);
+
+    clock.reset();
+
+    assert_eq!(clock.get_tick(), 0);
+}
+
+#[test]
+fn test_dales_law() {
+    let mut network = Network::new().with_dales_law();
+    assert!(network.is_enforcing_dales_law());
+
+    let excitatory = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+    let inhibitory = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+    let undeclared = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+
+    network.declare_source(Rc::clone(&excitatory), SourceSign::Excitatory);
+    network.declare_source(Rc::clone(&inhibitory), SourceSign::Inhibitory);
+
+    assert_eq!(
+        network.get_source_sign(&excitatory),
+        Some(SourceSign::Excitatory)
+    );
+    assert_eq!(network.get_source_sign(&undeclared), None);
+
+    let neuron = Neuron::new(10., 8., 0.1);
+
+    assert_eq!(
+        network.connect(
+            &neuron,
+            1.,
+            SynapticType::Excitatory,
+            Rc::clone(&excitatory)
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        network.connect(
+            &neuron,
+            1.,
+            SynapticType::Inhibitory,
+            Rc::clone(&inhibitory)
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        network.connect(
+            &neuron,
+            1.,
+            SynapticType::Inhibitory,
+            Rc::clone(&excitatory)
+        ),
+        Err(NetworkError::DalesLawViolation {
+            source_sign: SourceSign::Excitatory,
+            synaptic_type: SynapticType::Inhibitory,
+        })
+    );
+    assert_eq!(
+        network.connect(&neuron, 1., SynapticType::Shunting, Rc::clone(&excitatory)),
+        Err(NetworkError::DalesLawViolation {
+            source_sign: SourceSign::Excitatory,
+            synaptic_type: SynapticType::Shunting,
+        })
+    );
+    assert_eq!(
+        network.connect(
+            &neuron,
+            1.,
+            SynapticType::Excitatory,
+            Rc::clone(&inhibitory)
+        ),
+        Err(NetworkError::DalesLawViolation {
+            source_sign: SourceSign::Inhibitory,
+            synaptic_type: SynapticType::Excitatory,
+        })
+    );
+    assert_eq!(
+        network.connect(
+            &neuron,
+            1.,
+            SynapticType::Excitatory,
+            Rc::clone(&undeclared)
+        ),
+        Err(NetworkError::UndeclaredSource)
+    );
+
+    // Gating synapses don't have a sign
+    assert_eq!(
+        network.connect(&neuron, 1., SynapticType::Gating, Rc::clone(&inhibitory)),
+        Ok(())
+    );
+
+    // Rejected synapses were never created
+    assert_eq!(neuron.get_synapse_count(), 3);
+}
+
+#[test]
+fn test_connect_without_dales_law() {
+    let network = Network::new();
+    let neuron = Neuron::new(10., 8., 0.1);
+    let source = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+
+    assert_eq!(
+        network.connect(&neuron, 1., SynapticType::Excitatory, Rc::clone(&source)),
+        Ok(())
+    );
+    assert_eq!(
+        network.connect(&neuron, 1., SynapticType::Inhibitory, source),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_inhibitory_interneuron() {
+    let mut network = Network::new().with_dales_law();
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    network.declare_source(
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+        SourceSign::Excitatory,
+    );
+    network.declare_source(
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+        SourceSign::Excitatory,
+    );
+
+    let principal = Rc::new(Neuron::new(5., 8., 0.));
+    network.add_neuron(Rc::clone(&principal) as Rc<dyn Neuronic>);
+
+    let interneuron = network.add_inhibitory_interneuron(Neuron::new(5., 8., 0.));
+    assert_eq!(network.get_neuron_count(), 2);
+    assert_eq!(
+        network.get_source_sign(&(Rc::clone(&interneuron) as Rc<dyn NeuronicInput>)),
+        Some(SourceSign::Inhibitory)
+    );
+
+    network
+        .connect(
+            &*principal,
+            6.,
+            SynapticType::Excitatory,
+            Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+        )
+        .unwrap();
+    network
+        .connect(
+            &*interneuron,
+            6.,
+            SynapticType::Excitatory,
+            Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+        )
+        .unwrap();
+    network
+        .connect(
+            &*principal,
+            6.,
+            SynapticType::Inhibitory,
+            Rc::clone(&interneuron) as Rc<dyn NeuronicInput>,
+        )
+        .unwrap();
+
+    // The interneuron can't excite anything
+    assert!(network
+        .connect(
+            &*principal,
+            6.,
+            SynapticType::Excitatory,
+            Rc::clone(&interneuron) as Rc<dyn NeuronicInput>
+        )
+        .is_err());
+
+    s1.set_measure(0.5);
+    s2.set_measure(0.9);
+
+    // The interneuron hasn't fired yet, so the principal follows its sensor
+    network.run_static_cycle();
+    cmp_f32(principal.get_measure(ChargeCycle::Even), 0.5);
+    cmp_f32(interneuron.get_measure(ChargeCycle::Even), 0.9);
+
+    // Once it has, its inhibition cancels out the sensor
+    network.run_static_cycle();
+    cmp_f32(principal.get_measure(ChargeCycle::Odd), 0.);
+}
+
+/// Two excitatory sensors fully connected to a neuron by a
+/// Connector that makes every synapse inhibitory
+fn build_bulk_violation() -> (Network, Rc<Neuron>) {
+    let mut network = Network::new().with_dales_law();
+
+    let inputs: Vec<Rc<dyn NeuronicInput>> = (0..2)
+        .map(|_| Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>)
+        .collect();
+
+    for input in inputs.iter() {
+        network.declare_source(Rc::clone(input), SourceSign::Excitatory);
+    }
+
+    let neuron = Rc::new(Neuron::new(10., 8., 0.1));
+    network.add_neuron(Rc::clone(&neuron) as Rc<dyn Neuronic>);
+    assert_eq!(network.check_dales_law(), Ok(()));
+
+    Connector::new(Pattern::AllToAll, WeightInit::Constant(1.))
+        .with_inhibitory_fraction(1.)
+        .connect(&mut Rng::new(1), &inputs, &[Rc::clone(&neuron)]);
+
+    (network, neuron)
+}
+
+#[test]
+fn test_dales_law_bulk_build() {
+    let (network, neuron) = build_bulk_violation();
+
+    assert_eq!(neuron.get_synapse_count(), 2);
+    assert_eq!(
+        network.check_dales_law(),
+        Err(NetworkError::DalesLawViolation {
+            source_sign: SourceSign::Excitatory,
+            synaptic_type: SynapticType::Inhibitory,
+        })
+    );
+
+    // Without enforcement the same build is fine
+    let mut lawless = Network::new();
+    lawless.add_neuron(neuron as Rc<dyn Neuronic>);
+    assert_eq!(lawless.check_dales_law(), Ok(()));
+    lawless.run_cycle();
+}
+
+#[test]
+#[should_panic]
+fn test_dales_law_checked_before_step() {
+    let (mut network, _neuron) = build_bulk_violation();
+
+    network.run_static_cycle();
+}
+
+#[test]
+fn test_dales_law_checked_once() {
+    let mut network = Network::new().with_dales_law();
+
+    let source = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+    network.declare_source(Rc::clone(&source), SourceSign::Excitatory);
+
+    let neuron = Rc::new(Neuron::new(10., 8., 0.1));
+    neuron.create_synapse(1., SynapticType::Excitatory, Rc::clone(&source));
+    network.add_neuron(Rc::clone(&neuron) as Rc<dyn Neuronic>);
+    network.run_static_cycle();
+
+    // Steps don't walk the synapses again until something changes,
+    // so a synapse created behind the network's back needs a check
+    neuron.create_synapse(1., SynapticType::Inhibitory, Rc::clone(&source));
+    network.run_static_cycle();
+    assert!(network.check_dales_law().is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_declaring_source_rechecks_dales_law() {
+    let mut network = Network::new().with_dales_law();
+
+    let source = Rc::new(NeuronicSensor::new()) as Rc<dyn NeuronicInput>;
+    network.declare_source(Rc::clone(&source), SourceSign::Excitatory);
+
+    let neuron = Rc::new(Neuron::new(10., 8., 0.1));
+    neuron.create_synapse(1., SynapticType::Excitatory, Rc::clone(&source));
+    network.add_neuron(neuron as Rc<dyn Neuronic>);
+    network.run_static_cycle();
+
+    // The existing excitatory synapse now breaks the law
+    network.declare_source(source, SourceSign::Inhibitory);
+    network.run_static_cycle();
+}
@@ -0,0 +1,38 @@
+use std::cell::Cell;
+
+/// A reward or neuromodulator signal that scales learning in every
+/// Neuron it's attached to.  Share one between all neurons for a global
+/// signal, or give each neuron its own for a per-neuron signal.
+///
+/// The level is left as set, so a reward is usually set for the
+/// cycles it applies to, and then set back to 0
+pub struct Neuromodulator {
+    level: Cell<f32>,
+}
+
+impl Default for Neuromodulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Neuromodulator {
+    pub fn new() -> Neuromodulator {
+        Neuromodulator {
+            level: Cell::new(0.0),
+        }
+    }
+
+    /// Positive levels reinforce the correlations that built up the
+    /// eligibility traces, and negative levels reverse them
+    pub fn set_level(&self, level: f32) {
+        self.level.set(level);
+    }
+
+    pub fn get_level(&self) -> f32 {
+        self.level.get()
+    }
+}
+
+#[cfg(test)]
+mod neuromodulator_tests;
@@ -0,0 +1,76 @@
+use crate::neuromodulator::Neuromodulator;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::test_utils::cmp_f32;
+use std::rc::Rc;
+
+fn build_neuron(modulator: &Rc<Neuromodulator>, sensor: &Rc<NeuronicSensor>) -> Neuron {
+    let neuron = Neuron::new(1., 8., 0.1).with_reward_modulation(0.5, Rc::clone(modulator));
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(sensor) as Rc<dyn NeuronicInput>,
+    );
+    neuron
+}
+
+#[test]
+fn test_delayed_reward() {
+    let modulator = Rc::new(Neuromodulator::new());
+    let s1 = Rc::new(NeuronicSensor::new());
+    let neuron = build_neuron(&modulator, &s1);
+
+    // Without reward nothing is learned, but the trace builds up
+    s1.set_measure(0.5);
+    neuron.run_cycle(ChargeCycle::Even);
+
+    let delta = 0.1 * (8. - 4.) * ((2. * 0.5) - 0.5);
+    cmp_f32(neuron.get_weights()[0], 4.);
+    cmp_f32(neuron.get_eligibility_traces()[0], delta);
+
+    // The reward arrives a cycle after the input is gone,
+    // and is credited through the decayed trace
+    s1.set_measure(0.);
+    modulator.set_level(1.);
+    neuron.run_cycle(ChargeCycle::Odd);
+
+    cmp_f32(neuron.get_eligibility_traces()[0], 0.5 * delta);
+    cmp_f32(neuron.get_weights()[0], 4. + (0.5 * delta));
+
+    // A negative reward reverses the update
+    modulator.set_level(-1.);
+    neuron.run_cycle(ChargeCycle::Even);
+
+    cmp_f32(neuron.get_weights()[0], 4. + (0.5 * delta) - (0.25 * delta));
+
+    neuron.clear();
+    cmp_f32(neuron.get_eligibility_traces()[0], 0.);
+}
+
+#[test]
+fn test_global_and_local_modulators() {
+    let global = Rc::new(Neuromodulator::new());
+    let local = Rc::new(Neuromodulator::new());
+    let s1 = Rc::new(NeuronicSensor::new());
+
+    let n1 = build_neuron(&global, &s1);
+    let n2 = build_neuron(&global, &s1);
+    let n3 = build_neuron(&local, &s1);
+
+    s1.set_measure(0.5);
+    global.set_level(1.);
+
+    n1.run_cycle(ChargeCycle::Even);
+    n2.run_cycle(ChargeCycle::Even);
+    n3.run_cycle(ChargeCycle::Even);
+
+    let delta = 0.1 * (8. - 4.) * ((2. * 0.5) - 0.5);
+    cmp_f32(n1.get_weights()[0], 4. + delta);
+    cmp_f32(n2.get_weights()[0], 4. + delta);
+    cmp_f32(n3.get_weights()[0], 4.);
+}
+
+#[test]
+#[should_panic]
+fn test_trace_decay_out_of_bounds() {
+    Neuron::new(1., 8., 0.1).with_reward_modulation(1., Rc::new(Neuromodulator::new()));
+}
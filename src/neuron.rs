@@ -1,4 +1,13 @@
-use std::cell::RefCell;
+use crate::clock::Clock;
+use crate::kernel::SharedKernel;
+use crate::learning_schedule::LearningSchedule;
+use crate::neuromodulator::Neuromodulator;
+use crate::short_term_plasticity::ShortTermPlasticity;
+use crate::weight_bounds::{ClipPolicy, WeightBounds};
+use crate::weight_normalization::WeightNormalization;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cell::{Cell, Ref, RefCell, RefMut};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::rc::Rc;
@@ -55,6 +64,13 @@ pub trait Neuronic {
         synaptic_type: SynapticType,
         input: Rc<dyn NeuronicInput>,
     );
+
+    /// The input and type of every synapse, so a Network can check
+    /// them against Dale's law.  Neurons that ignore the types of
+    /// their synapses have nothing to check
+    fn get_synapse_inputs(&self) -> Vec<(Rc<dyn NeuronicInput>, SynapticType)> {
+        Vec::new()
+    }
 }
 
 /// Any object that functions as a pre-synaptic input
@@ -87,7 +103,7 @@ impl PartialEq for Impulse {
 
 impl PartialOrd for Impulse {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.measure.partial_cmp(&other.measure)
+        Some(self.cmp(other))
     }
 }
 
@@ -103,11 +119,24 @@ impl Ord for Impulse {
     }
 }
 
-/// Basic synapse type and synpase
-#[derive(Clone, Copy)]
+/// Basic synapse type and synpase.
+/// Only excitatory and inhibitory synapses take part in the threshold
+/// walk and learn.  The other types change how their compartment,
+/// the soma or a dendrite, walks and how the neuron learns, and keep
+/// the weight they were created with
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum SynapticType {
     Excitatory,
     Inhibitory,
+    /// Multiplies the weights of every impulse
+    /// in the compartment by measure * weight
+    Gating,
+    /// Divides the weights of every impulse in the compartment
+    /// by 1 + measure * weight, summed over shunting synapses
+    Shunting,
+    /// Scales the learning of the whole neuron by measure * weight,
+    /// summed over modulatory synapses, without affecting the measure
+    Modulatory,
 }
 
 pub struct Synapse {
@@ -115,6 +144,13 @@ pub struct Synapse {
     synaptic_type: SynapticType,
     pre_synaptic_neuron: Rc<dyn NeuronicInput>,
     last_impulse: Option<Impulse>,
+    eligibility: f32,
+    learning_rate: f32,
+    frozen: bool,
+    short_term_plasticity: Option<ShortTermPlasticity>,
+    efficacy: f32,
+    dendrite: Option<usize>,
+    kernel: Option<(Rc<SharedKernel>, usize)>,
 }
 
 impl Synapse {
@@ -124,26 +160,110 @@ impl Synapse {
             synaptic_type,
             pre_synaptic_neuron: neuron,
             last_impulse: None,
+            eligibility: 0.0,
+            learning_rate: 1.0,
+            frozen: false,
+            short_term_plasticity: None,
+            efficacy: 1.0,
+            dendrite: None,
+            kernel: None,
+        }
+    }
+
+    /// The synapse's own weight, or its weight in a shared kernel
+    pub fn get_weight(&self) -> f32 {
+        match self.kernel.as_ref() {
+            Some((kernel, index)) => kernel.get_weight(*index),
+            None => self.weight,
+        }
+    }
+
+    /// Setting the weight of a synapse in a shared kernel sets
+    /// it for every synapse sharing it
+    pub fn set_weight(&mut self, weight: f32) {
+        match self.kernel.as_ref() {
+            Some((kernel, index)) => kernel.set_weight(*index, weight),
+            None => self.weight = weight,
         }
     }
 
     pub fn generate_impulse(&mut self, cycle: ChargeCycle) -> Impulse {
         let measure = self.pre_synaptic_neuron.get_measure(cycle.prev_cycle());
+        let weight = self.get_weight() * self.efficacy;
 
         let impulse = match self.synaptic_type {
-            SynapticType::Excitatory => Impulse::new(measure, self.weight),
-            SynapticType::Inhibitory => Impulse::new(measure, -1.0 * self.weight),
+            SynapticType::Inhibitory => Impulse::new(measure, -weight),
+            _ => Impulse::new(measure, weight),
         };
 
+        if let Some(short_term_plasticity) = self.short_term_plasticity {
+            self.efficacy = short_term_plasticity.next_efficacy(self.efficacy, measure);
+        }
+
         self.last_impulse = Some(impulse);
 
         impulse
     }
 }
 
+/// The impulses of the soma or of a single dendrite, along with
+/// the factors from its gating and shunting synapses
+struct Compartment {
+    impulse_heap: BinaryHeap<Impulse>,
+    gate: f32,
+    shunt: f32,
+}
+
+impl Compartment {
+    fn new() -> Compartment {
+        Compartment {
+            impulse_heap: BinaryHeap::new(),
+            gate: 1.0,
+            shunt: 0.0,
+        }
+    }
+
+    fn add_impulse(&mut self, synaptic_type: SynapticType, impulse: Impulse) {
+        match synaptic_type {
+            SynapticType::Excitatory | SynapticType::Inhibitory => self.impulse_heap.push(impulse),
+            SynapticType::Gating => self.gate *= impulse.measure * impulse.weight,
+            SynapticType::Shunting => self.shunt += impulse.measure * impulse.weight,
+            // Modulation belongs to the whole neuron
+            SynapticType::Modulatory => (),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.impulse_heap.is_empty()
+    }
+
+    /// Get largest value impulses until the aggregate weight
+    /// surpasses the threshold, and return the measure of the
+    /// impulse that crossed it, or 0 if none did
+    fn threshold_walk(mut self, threshold: f32) -> f32 {
+        let scale = self.gate / (1.0 + self.shunt);
+        let mut total_weight = 0.0;
+
+        while let Some(impulse) = self.impulse_heap.pop() {
+            total_weight += impulse.weight * scale;
+
+            if total_weight >= threshold {
+                return impulse.measure;
+            }
+        }
+        0.0
+    }
+}
+
 /// Stores the Neuron's measure for different charge cycles
 pub struct InternalMeasure(f32, f32);
 
+impl Default for InternalMeasure {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl InternalMeasure {
     pub fn new() -> InternalMeasure {
         InternalMeasure(0.0, 0.0)
@@ -169,14 +289,205 @@ impl InternalMeasure {
     }
 }
 
+/// Homeostatic intrinsic plasticity.  The neuron keeps a running
+/// average of its measure, and nudges its fire_threshold so that
+/// average tracks a target: a neuron that fires too much becomes harder
+/// to fire, and a neuron that never fires becomes easier to fire
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Homeostasis {
+    target_measure: f32,
+    averaging_constant: f32,
+    adaptation_rate: f32,
+}
+
+impl Homeostasis {
+    /// averaging_constant is the weight of the newest measure in the
+    /// running average, and adaptation_rate scales how far the threshold
+    /// moves per unit of difference between the average and the target
+    pub fn new(target_measure: f32, averaging_constant: f32, adaptation_rate: f32) -> Homeostasis {
+        if !(0. ..=1.).contains(&averaging_constant) || averaging_constant == 0. {
+            panic!(
+                "Averaging constant {} out of bounds!  Must be in (0.0, 1.0]",
+                averaging_constant
+            );
+        }
+
+        Homeostasis {
+            target_measure,
+            averaging_constant,
+            adaptation_rate,
+        }
+    }
+
+    pub fn get_target_measure(&self) -> f32 {
+        self.target_measure
+    }
+
+    pub fn get_averaging_constant(&self) -> f32 {
+        self.averaging_constant
+    }
+
+    pub fn get_adaptation_rate(&self) -> f32 {
+        self.adaptation_rate
+    }
+}
+
+/// Three-factor learning.  Instead of being applied directly, each weight
+/// update is added to the synapse's eligibility trace, which decays by
+/// trace_decay every learning cycle.  The weight then moves by the trace
+/// scaled by the modulator level, so a reward arriving a few cycles
+/// later still credits the synapses that were active before it
+struct RewardModulation {
+    trace_decay: f32,
+    modulator: Rc<Neuromodulator>,
+}
+
+/// What happens to a Neuron while it's refractory
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RefractoryMode {
+    /// The neuron emits a measure of 0
+    Suppress,
+    /// The fire_threshold is raised by this amount,
+    /// so the neuron is fatigued rather than silenced
+    RaiseThreshold(f32),
+}
+
+/// After emitting a measure of at least trigger_measure, a Neuron is
+/// refractory for the next `period` cycles, static or not.  This lets
+/// oscillations and sequences emerge from neurons that would otherwise
+/// emit the same high measure every cycle
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Refractoriness {
+    trigger_measure: f32,
+    period: u32,
+    mode: RefractoryMode,
+}
+
+impl Refractoriness {
+    pub fn new(trigger_measure: f32, period: u32, mode: RefractoryMode) -> Refractoriness {
+        Refractoriness {
+            trigger_measure,
+            period,
+            mode,
+        }
+    }
+
+    pub fn get_trigger_measure(&self) -> f32 {
+        self.trigger_measure
+    }
+
+    pub fn get_period(&self) -> u32 {
+        self.period
+    }
+
+    pub fn get_mode(&self) -> RefractoryMode {
+        self.mode
+    }
+}
+
+/// How the soma of a Neuron with dendrites combines the
+/// measures of its dendrites.  Synapses created directly on the
+/// neuron, rather than on a dendrite, belong to the soma
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SomaRule {
+    /// Each dendrite's measure becomes an impulse with the dendrite's
+    /// weight, and these run a second threshold walk against the
+    /// fire_threshold together with the soma's own impulses
+    Threshold,
+    /// The largest measure of any dendrite, where the
+    /// soma's own synapses count as one more dendrite
+    Max,
+    /// The smallest measure of any dendrite, so the neuron only fires
+    /// when every dendrite fires, where the soma's own synapses
+    /// count as one more dendrite
+    Min,
+}
+
+/// How inhibitory synapses learn.  Their impulses carry negative
+/// weight in the threshold walk, so the excitatory rule, which
+/// strengthens synapses that drove the neuron, isn't necessarily
+/// what they should follow
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InhibitoryRule {
+    /// The same rule as excitatory synapses
+    Same,
+    /// Inhibitory synapses keep their weight
+    Fixed,
+    /// Inhibitory plasticity that balances excitation.  Each weight
+    /// moves by balance_rate * pre * (post - target_measure), so
+    /// inhibition grows while the neuron fires above the target and
+    /// shrinks while it fires below it.  balance_rate is annealed by
+    /// the neuron's learning schedule, like the learning_constant
+    Balanced {
+        target_measure: f32,
+        balance_rate: f32,
+    },
+}
+
+/// A branch of synapses with its own threshold walk
+struct Dendrite {
+    threshold: f32,
+    weight: f32,
+    measure: f32,
+}
+
+/// How a teacher's target measure is used, see Neuron::set_teacher
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TeacherMode {
+    /// The neuron's synapses learn towards the target, but
+    /// the neuron still emits the measure it naturally fired
+    Learning,
+    /// The target also replaces the measure the neuron emits,
+    /// so downstream neurons see the target as well
+    Propagate,
+}
+
+/// Everything about a Neuron that can be saved and restored.
+/// Synapse inputs are references to other parts of the graph,
+/// so only their weights are stored, in the order the synapses were created
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NeuronState {
+    pub fire_threshold: f32,
+    pub max_synapse_weight: f32,
+    pub learning_constant: f32,
+    pub weight_bounds: WeightBounds,
+    pub leak: f32,
+    pub refractoriness: Option<Refractoriness>,
+    pub homeostasis: Option<Homeostasis>,
+    pub average_measure: f32,
+    pub weights: Vec<f32>,
+}
+
 /// In this library, due to the post-synaptic neuron owning synapses
 /// There isn't a distinction between a plastic neuron and an actuator
 /// neuron.  And a SensorNeuron is basically anything that only implements
 /// NeuronicInput, so this simplifies implementation a butt-ton
 pub struct Neuron {
-    fire_threshold: f32,
-    max_synapse_weight: f32,
-    learning_constant: f32,
+    fire_threshold: Cell<f32>,
+    max_synapse_weight: Cell<f32>,
+    learning_constant: Cell<f32>,
+    learning_schedule: Option<(LearningSchedule, Rc<Clock>)>,
+    frozen: Cell<bool>,
+    weight_bounds: Cell<WeightBounds>,
+    weight_normalization: Cell<Option<WeightNormalization>>,
+    short_term_plasticity: Cell<Option<ShortTermPlasticity>>,
+    leak: Cell<f32>,
+    refractoriness: Cell<Option<Refractoriness>>,
+    refractory_ticks: Cell<u32>,
+    homeostasis: Cell<Option<Homeostasis>>,
+    average_measure: Cell<f32>,
+    reward_modulation: Option<RewardModulation>,
+    teacher: Cell<Option<(f32, TeacherMode)>>,
+    dendrites: RefCell<Vec<Dendrite>>,
+    soma_rule: Cell<SomaRule>,
+    inhibitory_rule: Cell<InhibitoryRule>,
+    modulation: Cell<Option<f32>>,
     synapses: RefCell<Vec<Synapse>>,
     internal_measure: RefCell<InternalMeasure>,
 }
@@ -184,17 +495,482 @@ pub struct Neuron {
 impl Neuron {
     pub fn new(fire_threshold: f32, max_synapse_weight: f32, learning_constant: f32) -> Neuron {
         Neuron {
-            fire_threshold,
-            max_synapse_weight,
-            learning_constant,
+            fire_threshold: Cell::new(fire_threshold),
+            max_synapse_weight: Cell::new(max_synapse_weight),
+            learning_constant: Cell::new(learning_constant),
+            learning_schedule: None,
+            frozen: Cell::new(false),
+            weight_bounds: Cell::new(WeightBounds::new(
+                0.0,
+                max_synapse_weight,
+                ClipPolicy::Reflect,
+            )),
+            weight_normalization: Cell::new(None),
+            short_term_plasticity: Cell::new(None),
+            leak: Cell::new(0.0),
+            refractoriness: Cell::new(None),
+            refractory_ticks: Cell::new(0),
+            homeostasis: Cell::new(None),
+            average_measure: Cell::new(0.0),
+            reward_modulation: None,
+            teacher: Cell::new(None),
+            dendrites: RefCell::new(Vec::new()),
+            soma_rule: Cell::new(SomaRule::Threshold),
+            inhibitory_rule: Cell::new(InhibitoryRule::Same),
+            modulation: Cell::new(None),
             synapses: RefCell::new(Vec::new()),
             internal_measure: RefCell::new(InternalMeasure::new()),
         }
     }
 
+    /// Anneals the learning_constant according to the
+    /// schedule, as time passes on the clock
+    pub fn with_learning_schedule(
+        mut self,
+        schedule: LearningSchedule,
+        clock: Rc<Clock>,
+    ) -> Neuron {
+        self.learning_schedule = Some((schedule, clock));
+        self
+    }
+
+    /// The learning constant after applying the learning schedule
+    pub fn get_learning_rate(&self) -> f32 {
+        self.apply_learning_schedule(self.learning_constant.get())
+    }
+
+    fn apply_learning_schedule(&self, rate: f32) -> f32 {
+        match self.learning_schedule.as_ref() {
+            Some((schedule, clock)) => schedule.get_rate(rate, clock.get_tick()),
+            None => rate,
+        }
+    }
+
+    /// A frozen neuron skips update_synapses entirely,
+    /// so neither its weights nor its threshold change
+    pub fn set_frozen(&self, frozen: bool) {
+        self.frozen.set(frozen);
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.get()
+    }
+
+    /// Scales the learning rate of a single synapse,
+    /// where index is the order the synapse was created in
+    pub fn set_synapse_learning_rate(&self, index: usize, learning_rate: f32) {
+        self.get_synapse_mut(index).learning_rate = learning_rate;
+    }
+
+    pub fn get_synapse_learning_rates(&self) -> Vec<f32> {
+        self.synapses
+            .borrow()
+            .iter()
+            .map(|synapse| synapse.learning_rate)
+            .collect()
+    }
+
+    /// Stops a single synapse from learning while the rest keep adapting
+    pub fn set_synapse_frozen(&self, index: usize, frozen: bool) {
+        self.get_synapse_mut(index).frozen = frozen;
+    }
+
+    pub fn is_synapse_frozen(&self, index: usize) -> bool {
+        self.get_synapse(index).frozen
+    }
+
+    fn get_synapse(&self, index: usize) -> Ref<'_, Synapse> {
+        let synapses = self.synapses.borrow();
+        check_synapse_index(index, synapses.len());
+
+        Ref::map(synapses, |synapses| &synapses[index])
+    }
+
+    fn get_synapse_mut(&self, index: usize) -> RefMut<'_, Synapse> {
+        let synapses = self.synapses.borrow_mut();
+        check_synapse_index(index, synapses.len());
+
+        RefMut::map(synapses, |synapses| &mut synapses[index])
+    }
+
+    /// Short-term plasticity for every synapse created after this
+    pub fn with_short_term_plasticity(self, short_term_plasticity: ShortTermPlasticity) -> Neuron {
+        self.short_term_plasticity.set(Some(short_term_plasticity));
+        self
+    }
+
+    /// Overrides the short-term plasticity of a single synapse
+    pub fn set_synapse_short_term_plasticity(
+        &self,
+        index: usize,
+        short_term_plasticity: Option<ShortTermPlasticity>,
+    ) {
+        let mut synapse = self.get_synapse_mut(index);
+
+        synapse.short_term_plasticity = short_term_plasticity;
+        synapse.efficacy = 1.0;
+    }
+
+    /// Current short-term efficacies of all synapses, in the
+    /// order the synapses were created
+    pub fn get_synapse_efficacies(&self) -> Vec<f32> {
+        self.synapses
+            .borrow()
+            .iter()
+            .map(|synapse| synapse.efficacy)
+            .collect()
+    }
+
+    /// Replaces the default bounds, which keep weights between 0 and
+    /// max_synapse_weight, reflecting weights that overshoot so they
+    /// don't get stuck at max_synapse_weight
+    pub fn with_weight_bounds(self, weight_bounds: WeightBounds) -> Neuron {
+        self.weight_bounds.set(weight_bounds);
+        self
+    }
+
+    pub fn get_weight_bounds(&self) -> WeightBounds {
+        self.weight_bounds.get()
+    }
+
+    /// Normalizes the excitatory weights after every update_synapses
+    pub fn with_weight_normalization(self, weight_normalization: WeightNormalization) -> Neuron {
+        self.weight_normalization.set(Some(weight_normalization));
+        self
+    }
+
+    pub fn get_weight_normalization(&self) -> Option<WeightNormalization> {
+        self.weight_normalization.get()
+    }
+
+    /// Scales the weights of every excitatory synapse that isn't frozen
+    /// or shared by the factor from the neuron's WeightNormalization, then clips
+    /// them to the excitatory weight bounds.  Does nothing for a neuron
+    /// without normalization
+    pub fn normalize_weights(&self) {
+        let weight_normalization = match self.weight_normalization.get() {
+            Some(weight_normalization) => weight_normalization,
+            None => return,
+        };
+        let (min, max) = self
+            .weight_bounds
+            .get()
+            .get_bounds(SynapticType::Excitatory);
+
+        let mut synapses = self.synapses.borrow_mut();
+        let mut normalized: Vec<&mut Synapse> = synapses
+            .iter_mut()
+            .filter(|synapse| {
+                !synapse.frozen
+                    && synapse.kernel.is_none()
+                    && synapse.synaptic_type == SynapticType::Excitatory
+            })
+            .collect();
+
+        let weights: Vec<f32> = normalized.iter().map(|synapse| synapse.weight).collect();
+        let factor = weight_normalization.get_factor(&weights);
+
+        for synapse in normalized.iter_mut() {
+            synapse.weight = (synapse.weight * factor).clamp(min, max);
+        }
+    }
+
+    /// Leaky integration.  Each cycle's measure becomes a blend of the
+    /// newly computed measure and the previous cycle's measure,
+    /// leak * previous + (1 - leak) * new, so the neuron remembers
+    /// recent inputs.  A leak of 0 is the default, memoryless behavior
+    pub fn with_leak(self, leak: f32) -> Neuron {
+        if !(0. ..1.).contains(&leak) {
+            panic!("Leak {} out of bounds!  Must be in [0.0, 1.0)", leak);
+        }
+
+        self.leak.set(leak);
+        self
+    }
+
+    pub fn get_leak(&self) -> f32 {
+        self.leak.get()
+    }
+
+    pub fn with_refractoriness(self, refractoriness: Refractoriness) -> Neuron {
+        self.refractoriness.set(Some(refractoriness));
+        self
+    }
+
+    pub fn get_refractoriness(&self) -> Option<Refractoriness> {
+        self.refractoriness.get()
+    }
+
+    /// How many more cycles the neuron will be refractory for
+    pub fn get_refractory_ticks(&self) -> u32 {
+        self.refractory_ticks.get()
+    }
+
+    pub fn is_refractory(&self) -> bool {
+        self.refractory_ticks.get() > 0
+    }
+
+    /// Enables an adaptive fire_threshold
+    pub fn with_homeostasis(self, homeostasis: Homeostasis) -> Neuron {
+        self.homeostasis.set(Some(homeostasis));
+        self
+    }
+
+    pub fn get_homeostasis(&self) -> Option<Homeostasis> {
+        self.homeostasis.get()
+    }
+
+    /// Makes learning depend on a reward signal, see RewardModulation.
+    /// trace_decay must be in [0, 1)
+    pub fn with_reward_modulation(
+        mut self,
+        trace_decay: f32,
+        modulator: Rc<Neuromodulator>,
+    ) -> Neuron {
+        if !(0. ..1.).contains(&trace_decay) {
+            panic!(
+                "Trace decay {} out of bounds!  Must be in [0.0, 1.0)",
+                trace_decay
+            );
+        }
+
+        self.reward_modulation = Some(RewardModulation {
+            trace_decay,
+            modulator,
+        });
+        self
+    }
+
+    /// Eligibility traces of all synapses, in the order
+    /// the synapses were created
+    pub fn get_eligibility_traces(&self) -> Vec<f32> {
+        self.synapses
+            .borrow()
+            .iter()
+            .map(|synapse| synapse.eligibility)
+            .collect()
+    }
+
+    pub fn with_soma_rule(self, soma_rule: SomaRule) -> Neuron {
+        self.soma_rule.set(soma_rule);
+        self
+    }
+
+    pub fn get_soma_rule(&self) -> SomaRule {
+        self.soma_rule.get()
+    }
+
+    pub fn with_inhibitory_rule(self, inhibitory_rule: InhibitoryRule) -> Neuron {
+        self.inhibitory_rule.set(inhibitory_rule);
+        self
+    }
+
+    pub fn get_inhibitory_rule(&self) -> InhibitoryRule {
+        self.inhibitory_rule.get()
+    }
+
+    /// Adds a dendrite with its own threshold, and returns its index.
+    /// The weight is only used by SomaRule::Threshold, as the weight
+    /// of the dendrite's impulse in the soma's threshold walk
+    pub fn add_dendrite(&self, threshold: f32, weight: f32) -> usize {
+        let mut dendrites = self.dendrites.borrow_mut();
+
+        dendrites.push(Dendrite {
+            threshold,
+            weight,
+            measure: 0.0,
+        });
+        dendrites.len() - 1
+    }
+
+    pub fn get_dendrite_count(&self) -> usize {
+        self.dendrites.borrow().len()
+    }
+
+    /// Measures of every dendrite from the last cycle that was run
+    pub fn get_dendrite_measures(&self) -> Vec<f32> {
+        self.dendrites
+            .borrow()
+            .iter()
+            .map(|dendrite| dendrite.measure)
+            .collect()
+    }
+
+    /// Creates a synapse on a dendrite instead of on the soma.
+    /// Learning still follows the measure of the whole neuron
+    pub fn create_dendritic_synapse(
+        &self,
+        dendrite: usize,
+        starting_weight: f32,
+        synaptic_type: SynapticType,
+        input: Rc<dyn NeuronicInput>,
+    ) {
+        if dendrite >= self.get_dendrite_count() {
+            panic!(
+                "No dendrite at index {}, neuron has {} dendrites",
+                dendrite,
+                self.get_dendrite_count()
+            );
+        }
+
+        self.create_synapse(starting_weight, synaptic_type, input);
+        self.synapses.borrow_mut().last_mut().unwrap().dendrite = Some(dendrite);
+    }
+
+    /// Creates a synapse whose weight is the weight at index in a
+    /// shared kernel, with the kernel's synaptic type.  See SharedKernel
+    pub fn create_shared_synapse(
+        &self,
+        kernel: &Rc<SharedKernel>,
+        index: usize,
+        input: Rc<dyn NeuronicInput>,
+    ) {
+        // Panics if the index is out of bounds
+        let weight = kernel.get_weight(index);
+
+        self.create_synapse(weight, kernel.get_synaptic_type(), input);
+        self.synapses.borrow_mut().last_mut().unwrap().kernel = Some((Rc::clone(kernel), index));
+    }
+
+    /// Summed measure * weight of the modulatory synapses on the last
+    /// cycle, or None if the neuron has no modulatory synapses
+    pub fn get_modulation(&self) -> Option<f32> {
+        self.modulation.get()
+    }
+
+    /// Clamps the measure used for learning to a target, until the
+    /// teacher is cleared.  This is for training readout neurons
+    /// towards a label instead of towards whatever they fired
+    pub fn set_teacher(&self, target: f32, mode: TeacherMode) {
+        // Make sure target is only between 0 and 1
+        if !(0. ..=1.).contains(&target) {
+            panic!(
+                "Target {} out of bounds!  Target must be between 0.0 and 1.0",
+                target
+            );
+        }
+        self.teacher.set(Some((target, mode)));
+    }
+
+    pub fn clear_teacher(&self) {
+        self.teacher.set(None);
+    }
+
+    pub fn get_teacher(&self) -> Option<(f32, TeacherMode)> {
+        self.teacher.get()
+    }
+
+    pub fn get_fire_threshold(&self) -> f32 {
+        self.fire_threshold.get()
+    }
+
+    /// Running average of the measure, only tracked with homeostasis
+    pub fn get_average_measure(&self) -> f32 {
+        self.average_measure.get()
+    }
+
+    /// Moves the fire_threshold based on the measure of this cycle.
+    /// This is part of update_synapses, but can be run on its own
+    /// for neurons that shouldn't learn this cycle but should still
+    /// count the cycle towards their firing rate.  A frozen
+    /// neuron's threshold doesn't move
+    pub fn update_threshold(&self, cycle: ChargeCycle) {
+        if self.frozen.get() {
+            return;
+        }
+
+        if let Some(homeostasis) = self.homeostasis.get() {
+            let measure = self.internal_measure.borrow().get_measure(cycle);
+
+            let average = self.average_measure.get()
+                + homeostasis.averaging_constant * (measure - self.average_measure.get());
+            self.average_measure.set(average);
+
+            let threshold = self.fire_threshold.get()
+                + homeostasis.adaptation_rate * (average - homeostasis.target_measure);
+
+            // A negative threshold would behave the same as a zero threshold
+            self.fire_threshold.set(threshold.max(0.0));
+        }
+    }
+
+    pub fn save_state(&self) -> NeuronState {
+        NeuronState {
+            fire_threshold: self.fire_threshold.get(),
+            max_synapse_weight: self.max_synapse_weight.get(),
+            learning_constant: self.learning_constant.get(),
+            weight_bounds: self.weight_bounds.get(),
+            leak: self.leak.get(),
+            refractoriness: self.refractoriness.get(),
+            homeostasis: self.homeostasis.get(),
+            average_measure: self.average_measure.get(),
+            weights: self.get_weights(),
+        }
+    }
+
+    /// Restores a saved state into a neuron, which must
+    /// already have the same number of synapses
+    pub fn load_state(&self, state: &NeuronState) {
+        self.set_weights(&state.weights);
+
+        self.fire_threshold.set(state.fire_threshold);
+        self.max_synapse_weight.set(state.max_synapse_weight);
+        self.learning_constant.set(state.learning_constant);
+        self.weight_bounds.set(state.weight_bounds);
+        self.leak.set(state.leak);
+        self.refractoriness.set(state.refractoriness);
+        self.homeostasis.set(state.homeostasis);
+        self.average_measure.set(state.average_measure);
+    }
+
     pub fn get_synapse_count(&self) -> usize {
         self.synapses.borrow().len()
     }
+
+    /// Overwrites the measure computed for a cycle, used by
+    /// layers that post-process the measures of their neurons
+    pub(crate) fn set_measure(&self, cycle: ChargeCycle, measure: f32) {
+        self.internal_measure
+            .borrow_mut()
+            .set_measure(cycle, measure);
+    }
+
+    /// Returns the weights of all synapses, in the order
+    /// the synapses were created
+    pub fn get_weights(&self) -> Vec<f32> {
+        self.synapses
+            .borrow()
+            .iter()
+            .map(|synapse| synapse.get_weight())
+            .collect()
+    }
+
+    pub fn get_synaptic_types(&self) -> Vec<SynapticType> {
+        self.synapses
+            .borrow()
+            .iter()
+            .map(|synapse| synapse.synaptic_type)
+            .collect()
+    }
+
+    /// Overwrites the weights of all synapses, in the order
+    /// the synapses were created.  Synapses in a shared kernel
+    /// overwrite the kernel's weight
+    pub fn set_weights(&self, weights: &[f32]) {
+        let mut synapses = self.synapses.borrow_mut();
+
+        if weights.len() != synapses.len() {
+            panic!(
+                "Got {} weights for a neuron with {} synapses",
+                weights.len(),
+                synapses.len()
+            );
+        }
+
+        for (synapse, weight) in synapses.iter_mut().zip(weights.iter()) {
+            synapse.set_weight(*weight);
+        }
+    }
 }
 
 impl NeuronicInput for Neuron {
@@ -208,65 +984,204 @@ impl Neuronic for Neuron {
         let mut synapses = self.synapses.borrow_mut();
         let mut internal_measure = self.internal_measure.borrow_mut();
 
-        let mut impulse_heap: BinaryHeap<Impulse> = BinaryHeap::new();
+        let mut dendrites = self.dendrites.borrow_mut();
 
-        // Throw all impulses into the heap
+        let mut soma = Compartment::new();
+        let mut dendrite_compartments: Vec<Compartment> =
+            dendrites.iter().map(|_| Compartment::new()).collect();
+        let mut modulation = None;
+
+        // Throw all impulses into the heap of their compartment
         for synapse in synapses.iter_mut() {
-            impulse_heap.push(synapse.generate_impulse(cycle));
+            let impulse = synapse.generate_impulse(cycle);
+
+            if let SynapticType::Modulatory = synapse.synaptic_type {
+                modulation = Some(modulation.unwrap_or(0.0) + (impulse.measure * impulse.weight));
+            }
+
+            match synapse.dendrite {
+                Some(index) => {
+                    dendrite_compartments[index].add_impulse(synapse.synaptic_type, impulse)
+                }
+                None => soma.add_impulse(synapse.synaptic_type, impulse),
+            }
         }
 
-        // Get largest value impulses until the
-        // aggregate weight surpasses the fire_threshold
-        let mut total_weight = 0.0;
+        self.modulation.set(modulation);
+
+        let refractoriness = self.refractoriness.get();
+        let refractory = self.refractory_ticks.get() > 0;
+
+        let mut fire_threshold = self.fire_threshold.get();
+        if let Some(Refractoriness {
+            mode: RefractoryMode::RaiseThreshold(raise),
+            ..
+        }) = refractoriness
+        {
+            if refractory {
+                fire_threshold += raise;
+            }
+        }
+
+        // Each dendrite runs its own threshold walk
+        for (dendrite, compartment) in dendrites.iter_mut().zip(dendrite_compartments) {
+            dendrite.measure = compartment.threshold_walk(dendrite.threshold);
+        }
+
+        let mut final_measure = if dendrites.is_empty() {
+            soma.threshold_walk(fire_threshold)
+        } else {
+            match self.soma_rule.get() {
+                SomaRule::Threshold => {
+                    for dendrite in dendrites.iter() {
+                        soma.add_impulse(
+                            SynapticType::Excitatory,
+                            Impulse::new(dendrite.measure, dendrite.weight),
+                        );
+                    }
+                    soma.threshold_walk(fire_threshold)
+                }
+                SomaRule::Max | SomaRule::Min => {
+                    // Synapses on the soma act like one more dendrite
+                    let soma_measure = if soma.is_empty() {
+                        None
+                    } else {
+                        Some(soma.threshold_walk(fire_threshold))
+                    };
+
+                    let measures = dendrites
+                        .iter()
+                        .map(|dendrite| dendrite.measure)
+                        .chain(soma_measure);
+
+                    if let SomaRule::Max = self.soma_rule.get() {
+                        measures.fold(0.0, f32::max)
+                    } else {
+                        measures.fold(1.0, f32::min)
+                    }
+                }
+            }
+        };
 
-        let final_measure;
-        loop {
-            if let Some(impulse) = impulse_heap.pop() {
-                total_weight += impulse.weight;
+        let leak = self.leak.get();
+        if leak > 0.0 {
+            final_measure = (leak * internal_measure.get_measure(cycle.prev_cycle()))
+                + ((1.0 - leak) * final_measure);
+        }
 
-                if total_weight >= self.fire_threshold {
-                    final_measure = impulse.measure;
-                    internal_measure.set_measure(cycle, final_measure);
-                    break;
+        if let Some(refractoriness) = refractoriness {
+            if refractory {
+                if let RefractoryMode::Suppress = refractoriness.mode {
+                    final_measure = 0.0;
                 }
-            } else {
-                final_measure = 0.0;
-                internal_measure.set_measure(cycle, final_measure);
-                break;
+                self.refractory_ticks.set(self.refractory_ticks.get() - 1);
+            } else if final_measure >= refractoriness.trigger_measure {
+                self.refractory_ticks.set(refractoriness.period);
             }
         }
+
+        // A propagating teacher replaces the measure outright
+        if let Some((target, TeacherMode::Propagate)) = self.teacher.get() {
+            final_measure = target;
+        }
+
+        internal_measure.set_measure(cycle, final_measure);
         final_measure
     }
 
     /// This is about the most basic update mechanism possible.
     /// Basically just a spring weighted by a measure
     fn update_synapses(&self, cycle: ChargeCycle) {
-        let fired_measure = self.internal_measure.borrow().get_measure(cycle);
+        if self.frozen.get() {
+            return;
+        }
+
+        // Synapses learn towards the teacher's target, if there is one
+        let fired_measure = match self.teacher.get() {
+            Some((target, _)) => target,
+            None => self.internal_measure.borrow().get_measure(cycle),
+        };
+        let learning_rate = self.get_learning_rate();
+        let max_synapse_weight = self.max_synapse_weight.get();
+        let weight_bounds = self.weight_bounds.get();
+        let modulation = self.modulation.get().unwrap_or(1.0);
+        let inhibitory_rule = self.inhibitory_rule.get();
 
         for synapse in self.synapses.borrow_mut().iter_mut() {
-            let synapse_measure = synapse.last_impulse.unwrap().measure;
+            if synapse.frozen {
+                continue;
+            }
 
-            if synapse_measure < fired_measure {
-                synapse.weight += self.learning_constant
-                    * (self.max_synapse_weight - synapse.weight)
-                    * synapse_measure;
-            } else {
-                synapse.weight += self.learning_constant
-                    * (self.max_synapse_weight - synapse.weight)
-                    * (fired_measure - synapse_measure);
-                // * ((2.0 * fired_measure) - synapse_measure);
+            match synapse.synaptic_type {
+                SynapticType::Excitatory | SynapticType::Inhibitory => (),
+                _ => continue,
             }
 
-            if synapse.weight > self.max_synapse_weight {
-                synapse.weight = self.max_synapse_weight - 0.1; // Just under max weight so that it still updates
-            } else if synapse.weight < 0.0 {
-                synapse.weight = 0.0;
+            let synapse_measure = synapse.last_impulse.unwrap().measure;
+            let weight = synapse.get_weight();
+            let learning_constant = learning_rate * synapse.learning_rate;
+
+            let rule = match synapse.synaptic_type {
+                SynapticType::Inhibitory => inhibitory_rule,
+                _ => InhibitoryRule::Same,
+            };
+
+            let delta = match rule {
+                InhibitoryRule::Same => {
+                    if synapse_measure < fired_measure {
+                        learning_constant * (max_synapse_weight - weight) * synapse_measure
+                    } else {
+                        learning_constant
+                            * (max_synapse_weight - weight)
+                            * ((2.0 * fired_measure) - synapse_measure)
+                    }
+                }
+                InhibitoryRule::Fixed => continue,
+                InhibitoryRule::Balanced {
+                    target_measure,
+                    balance_rate,
+                } => {
+                    self.apply_learning_schedule(balance_rate)
+                        * synapse.learning_rate
+                        * synapse_measure
+                        * (fired_measure - target_measure)
+                }
+            };
+            let delta = delta * modulation;
+
+            let delta = match self.reward_modulation.as_ref() {
+                Some(reward_modulation) => {
+                    synapse.eligibility =
+                        (reward_modulation.trace_decay * synapse.eligibility) + delta;
+                    reward_modulation.modulator.get_level() * synapse.eligibility
+                }
+                None => delta,
+            };
+
+            // Shared weights are only changed once every
+            // neuron sharing them has had its say
+            match synapse.kernel.as_ref() {
+                Some((kernel, index)) => kernel.add_update(*index, delta),
+                None => synapse.weight = weight_bounds.apply(synapse.synaptic_type, weight, delta),
             }
         }
+
+        self.normalize_weights();
+        self.update_threshold(cycle);
     }
 
     fn clear(&self) {
         self.internal_measure.borrow_mut().clear();
+        self.refractory_ticks.set(0);
+
+        for dendrite in self.dendrites.borrow_mut().iter_mut() {
+            dendrite.measure = 0.0;
+        }
+
+        for synapse in self.synapses.borrow_mut().iter_mut() {
+            synapse.eligibility = 0.0;
+            synapse.efficacy = 1.0;
+        }
     }
 
     fn create_synapse(
@@ -275,9 +1190,23 @@ impl Neuronic for Neuron {
         synaptic_type: SynapticType,
         input: Rc<dyn NeuronicInput>,
     ) {
+        let mut synapse = Synapse::new(starting_weight, synaptic_type, input);
+        synapse.short_term_plasticity = self.short_term_plasticity.get();
+
+        self.synapses.borrow_mut().push(synapse);
+    }
+
+    fn get_synapse_inputs(&self) -> Vec<(Rc<dyn NeuronicInput>, SynapticType)> {
         self.synapses
-            .borrow_mut()
-            .push(Synapse::new(starting_weight, synaptic_type, input));
+            .borrow()
+            .iter()
+            .map(|synapse| {
+                (
+                    Rc::clone(&synapse.pre_synaptic_neuron),
+                    synapse.synaptic_type,
+                )
+            })
+            .collect()
     }
 }
 
@@ -287,6 +1216,12 @@ pub struct NeuronicSensor {
     measure: RefCell<f32>,
 }
 
+impl Default for NeuronicSensor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl NeuronicSensor {
     pub fn new() -> NeuronicSensor {
         NeuronicSensor {
@@ -296,7 +1231,7 @@ impl NeuronicSensor {
 
     pub fn set_measure(&self, measure: f32) {
         // Make sure measure is only between 0 and 1
-        if measure > 1. || measure < 0. {
+        if !(0. ..=1.).contains(&measure) {
             panic!(
                 "Measure {} out of bounds!  Measure must be between 0.0 and 1.0",
                 measure
@@ -312,5 +1247,14 @@ impl NeuronicInput for NeuronicSensor {
     }
 }
 
+fn check_synapse_index(index: usize, synapse_count: usize) {
+    if index >= synapse_count {
+        panic!(
+            "No synapse at index {}, neuron has {} synapses",
+            index, synapse_count
+        );
+    }
+}
+
 #[cfg(test)]
 mod neuron_tests;
@@ -1,18 +1,12 @@
-use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::clock::Clock;
+use crate::learning_schedule::LearningSchedule;
+use crate::neuron::{
+    ChargeCycle, Homeostasis, InhibitoryRule, Neuron, NeuronState, Neuronic, NeuronicInput,
+    NeuronicSensor, Refractoriness, RefractoryMode, SomaRule, SynapticType,
+};
+use crate::test_utils::cmp_f32;
 use std::rc::Rc;
 
-/// Utility method that compares f32 to
-/// three decimal places
-fn cmp_f32(f1: f32, f2: f32) {
-    assert_eq!(
-        (f1 * 1000.).floor(),
-        (f2 * 1000.).floor(),
-        "{} does not equal {}",
-        f1,
-        f2
-    );
-}
-
 #[test]
 fn test_neuronic_sensor() {
     let sensor = NeuronicSensor::new();
@@ -237,7 +231,7 @@ fn test_update_synapses() {
 
     {
         let synapses = neuron.synapses.borrow();
-        s1_weight = synapses.get(0).unwrap().weight;
+        s1_weight = synapses.first().unwrap().weight;
         s2_weight = synapses.get(1).unwrap().weight;
         s3_weight = synapses.get(2).unwrap().weight;
         s4_weight = synapses.get(3).unwrap().weight;
@@ -262,17 +256,17 @@ fn test_update_synapses() {
     neuron.update_synapses(ChargeCycle::Odd);
 
     let s1_calculated_weight =
-        s1_weight + (learning_constant * (max_weight - s1_weight) * -1. * s1_measure);
+        s1_weight + (learning_constant * (max_weight - s1_weight) * -s1_measure);
     let s2_calculated_weight =
-        s2_weight + (learning_constant * (max_weight - s2_weight) * -1. * s2_measure);
+        s2_weight + (learning_constant * (max_weight - s2_weight) * -s2_measure);
     let s3_calculated_weight =
-        s3_weight + (learning_constant * (max_weight - s3_weight) * -1. * s3_measure);
+        s3_weight + (learning_constant * (max_weight - s3_weight) * -s3_measure);
     let s4_calculated_weight =
-        s4_weight + (learning_constant * (max_weight - s4_weight) * -1. * s4_measure);
+        s4_weight + (learning_constant * (max_weight - s4_weight) * -s4_measure);
 
     {
         let synapses = neuron.synapses.borrow();
-        s1_weight = synapses.get(0).unwrap().weight;
+        s1_weight = synapses.first().unwrap().weight;
         s2_weight = synapses.get(1).unwrap().weight;
         s3_weight = synapses.get(2).unwrap().weight;
         s4_weight = synapses.get(3).unwrap().weight;
@@ -307,7 +301,7 @@ fn test_update_synapses() {
 
     {
         let synapses = neuron.synapses.borrow();
-        s1_weight = synapses.get(0).unwrap().weight;
+        s1_weight = synapses.first().unwrap().weight;
         s2_weight = synapses.get(1).unwrap().weight;
         s3_weight = synapses.get(2).unwrap().weight;
         s4_weight = synapses.get(3).unwrap().weight;
@@ -572,3 +566,621 @@ fn test_clear() {
     cmp_f32(neuron.get_measure(ChargeCycle::Even), 0.0);
     cmp_f32(neuron.get_measure(ChargeCycle::Odd), 0.0);
 }
+
+#[test]
+fn test_get_set_weights() {
+    let neuron = Neuron::new(10., 8., 0.1);
+
+    neuron.create_synapse(2., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+    neuron.create_synapse(3., SynapticType::Inhibitory, Rc::new(NeuronicSensor::new()));
+
+    assert_eq!(neuron.get_weights(), vec![2., 3.]);
+
+    neuron.set_weights(&[5., 1.]);
+
+    assert_eq!(neuron.get_weights(), vec![5., 1.]);
+}
+
+#[test]
+#[should_panic]
+fn test_set_weights_wrong_length() {
+    let neuron = Neuron::new(10., 8., 0.1);
+
+    neuron.create_synapse(2., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+
+    neuron.set_weights(&[5., 1.]);
+}
+
+#[test]
+fn test_homeostasis_lowers_threshold() {
+    let homeostasis = Homeostasis::new(0.3, 0.5, 2.);
+    let neuron = Neuron::new(10., 8., 0.).with_homeostasis(homeostasis);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        2.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.5);
+
+    // Static cycles don't adapt the threshold
+    neuron.run_static_cycle(ChargeCycle::Even);
+    cmp_f32(neuron.get_fire_threshold(), 10.);
+
+    // The neuron can't fire at first, so the threshold
+    // falls until the single synapse is enough
+    let mut cycle = ChargeCycle::Even;
+    let mut fired = false;
+    for _ in 0..30 {
+        if neuron.run_cycle(cycle) > 0. {
+            fired = true;
+            break;
+        }
+        cycle = cycle.next_cycle();
+    }
+
+    assert!(fired, "Neuron never fired");
+    assert!(neuron.get_fire_threshold() <= 2.);
+}
+
+#[test]
+fn test_homeostasis_raises_threshold() {
+    let homeostasis = Homeostasis::new(0.3, 0.5, 2.);
+    let neuron = Neuron::new(1., 8., 0.).with_homeostasis(homeostasis);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.9);
+
+    // Average is now 0.45, which is above the target
+    cmp_f32(neuron.get_average_measure(), 0.45);
+    cmp_f32(neuron.get_fire_threshold(), 1. + (2. * (0.45 - 0.3)));
+
+    // Firing all the time pushes the threshold
+    // past the weight of the only synapse
+    let mut cycle = ChargeCycle::Odd;
+    let mut silenced = false;
+    for _ in 0..30 {
+        if neuron.run_cycle(cycle) == 0. {
+            silenced = true;
+            break;
+        }
+        cycle = cycle.next_cycle();
+    }
+
+    assert!(silenced, "Neuron never stopped firing");
+    assert!(neuron.get_fire_threshold() > 4.);
+}
+
+#[test]
+#[should_panic]
+fn test_homeostasis_nan_averaging_constant() {
+    Homeostasis::new(0.3, f32::NAN, 2.);
+}
+
+#[test]
+fn test_save_load_state() {
+    let homeostasis = Homeostasis::new(0.3, 0.5, 2.);
+    let neuron = Neuron::new(1., 8., 0.1).with_homeostasis(homeostasis);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+
+    neuron.run_cycle(ChargeCycle::Even);
+
+    let state: NeuronState = neuron.save_state();
+
+    assert_eq!(state.homeostasis, Some(homeostasis));
+    assert_eq!(state.weights, neuron.get_weights());
+    cmp_f32(state.fire_threshold, neuron.get_fire_threshold());
+
+    let restored = Neuron::new(10., 2., 2.);
+    restored.create_synapse(
+        1.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    restored.load_state(&state);
+
+    assert_eq!(restored.save_state(), state);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_state() {
+    let homeostasis = Homeostasis::new(0.3, 0.5, 2.);
+    let neuron = Neuron::new(1., 8., 0.1).with_homeostasis(homeostasis);
+
+    neuron.create_synapse(4., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+
+    let state = neuron.save_state();
+    let json = serde_json::to_string(&state).unwrap();
+
+    assert_eq!(serde_json::from_str::<NeuronState>(&json).unwrap(), state);
+}
+
+#[test]
+fn test_leaky_integration() {
+    let leak = 0.5;
+    let neuron = Neuron::new(1., 8., 0.).with_leak(leak);
+
+    assert_eq!(neuron.get_leak(), leak);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.8);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.4);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.6);
+
+    // Once the input is gone, the measure decays instead of dropping to 0
+    s1.set_measure(0.);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.3);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.15);
+
+    neuron.clear();
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.);
+}
+
+#[test]
+#[should_panic]
+fn test_leak_out_of_bounds() {
+    Neuron::new(1., 8., 0.).with_leak(1.);
+}
+
+#[test]
+fn test_refractory_suppression() {
+    let refractoriness = Refractoriness::new(0.8, 2, RefractoryMode::Suppress);
+    let neuron = Neuron::new(1., 8., 0.).with_refractoriness(refractoriness);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+
+    assert!(!neuron.is_refractory());
+
+    // A constant input now makes the neuron oscillate
+    let expected = [0.9, 0., 0., 0.9, 0., 0.];
+    let expected_ticks = [2, 1, 0, 2, 1, 0];
+
+    let mut cycle = ChargeCycle::Even;
+    for (measure, ticks) in expected.iter().zip(expected_ticks.iter()) {
+        cmp_f32(neuron.run_cycle(cycle), *measure);
+        assert_eq!(neuron.get_refractory_ticks(), *ticks);
+        cycle = cycle.next_cycle();
+    }
+
+    // Measures below the trigger don't start a refractory period
+    s1.set_measure(0.5);
+    cmp_f32(neuron.run_cycle(cycle), 0.5);
+    assert!(!neuron.is_refractory());
+
+    s1.set_measure(0.9);
+    neuron.run_cycle(cycle.next_cycle());
+    assert!(neuron.is_refractory());
+
+    neuron.clear();
+    assert!(!neuron.is_refractory());
+}
+
+#[test]
+fn test_refractory_fatigue() {
+    let refractoriness = Refractoriness::new(0.8, 1, RefractoryMode::RaiseThreshold(5.));
+    let neuron = Neuron::new(1., 8., 0.).with_refractoriness(refractoriness);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+    s2.set_measure(0.5);
+
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.9);
+
+    // The raised threshold needs both synapses
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Odd), 0.5);
+
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.9);
+}
+
+/// Builds a neuron with two dendrites of two sensors each.
+/// A dendrite only fires when both of its sensors are active
+fn build_dendritic_neuron(soma_rule: SomaRule) -> (Neuron, Vec<Rc<NeuronicSensor>>) {
+    let neuron = Neuron::new(10., 8., 0.).with_soma_rule(soma_rule);
+    let sensors: Vec<Rc<NeuronicSensor>> = (0..4).map(|_| Rc::new(NeuronicSensor::new())).collect();
+
+    for (i, sensor) in sensors.iter().enumerate() {
+        if i % 2 == 0 {
+            neuron.add_dendrite(5., 5.);
+        }
+
+        neuron.create_dendritic_synapse(
+            i / 2,
+            3.,
+            SynapticType::Excitatory,
+            Rc::clone(sensor) as Rc<dyn NeuronicInput>,
+        );
+    }
+
+    (neuron, sensors)
+}
+
+fn set_sensors(sensors: &[Rc<NeuronicSensor>], measures: &[f32]) {
+    for (sensor, measure) in sensors.iter().zip(measures.iter()) {
+        sensor.set_measure(*measure);
+    }
+}
+
+#[test]
+fn test_dendrites_threshold_soma() {
+    let (neuron, sensors) = build_dendritic_neuron(SomaRule::Threshold);
+
+    assert_eq!(neuron.get_dendrite_count(), 2);
+    assert_eq!(neuron.get_synapse_count(), 4);
+
+    // Both groups active: each dendrite fires the lower of its two
+    // measures, and the soma needs both dendrites to reach 10
+    set_sensors(&sensors, &[0.9, 0.7, 0.8, 0.6]);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.6);
+    assert_eq!(neuron.get_dendrite_measures(), vec![0.7, 0.6]);
+
+    // Only one group active isn't enough, even though
+    // there's more total synaptic weight active than 10
+    set_sensors(&sensors, &[0.9, 0.7, 0.8, 0.]);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Odd), 0.);
+}
+
+#[test]
+fn test_dendrites_min_max_soma() {
+    let (min_neuron, min_sensors) = build_dendritic_neuron(SomaRule::Min);
+    let (max_neuron, max_sensors) = build_dendritic_neuron(SomaRule::Max);
+
+    set_sensors(&min_sensors, &[0.9, 0.7, 0.8, 0.6]);
+    set_sensors(&max_sensors, &[0.9, 0.7, 0.8, 0.6]);
+    cmp_f32(min_neuron.run_static_cycle(ChargeCycle::Even), 0.6);
+    cmp_f32(max_neuron.run_static_cycle(ChargeCycle::Even), 0.7);
+
+    set_sensors(&min_sensors, &[0.9, 0.7, 0., 0.6]);
+    set_sensors(&max_sensors, &[0.9, 0.7, 0., 0.6]);
+    cmp_f32(min_neuron.run_static_cycle(ChargeCycle::Odd), 0.);
+    cmp_f32(max_neuron.run_static_cycle(ChargeCycle::Odd), 0.7);
+
+    // Synapses on the soma count as another dendrite
+    let soma_sensor = Rc::new(NeuronicSensor::new());
+    soma_sensor.set_measure(0.95);
+    max_neuron.create_synapse(
+        12.,
+        SynapticType::Excitatory,
+        soma_sensor as Rc<dyn NeuronicInput>,
+    );
+
+    cmp_f32(max_neuron.run_static_cycle(ChargeCycle::Even), 0.95);
+
+    max_neuron.clear();
+    assert_eq!(max_neuron.get_dendrite_measures(), vec![0., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn test_missing_dendrite() {
+    let neuron = Neuron::new(10., 8., 0.);
+
+    neuron.create_dendritic_synapse(
+        0,
+        1.,
+        SynapticType::Excitatory,
+        Rc::new(NeuronicSensor::new()),
+    );
+}
+
+#[test]
+fn test_gating_synapse() {
+    let neuron = Neuron::new(5., 8., 0.);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let gate = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        1.,
+        SynapticType::Gating,
+        Rc::clone(&gate) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.8);
+
+    // A fully open gate doesn't change anything
+    gate.set_measure(1.);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.8);
+
+    // A half open gate halves the weight of the excitatory synapse
+    gate.set_measure(0.5);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.);
+
+    // A closed gate silences the neuron entirely
+    gate.set_measure(0.);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.);
+
+    // Gating synapses never learn
+    assert_eq!(neuron.get_weights()[1], 1.);
+}
+
+#[test]
+fn test_shunting_synapse() {
+    let neuron = Neuron::new(5., 8., 0.);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let shunt = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        1.,
+        SynapticType::Shunting,
+        Rc::clone(&shunt) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.8);
+
+    shunt.set_measure(0.);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.8);
+
+    // 6 / (1 + 0.1) is still above the threshold
+    shunt.set_measure(0.1);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Odd), 0.8);
+
+    // 6 / (1 + 1) is not
+    shunt.set_measure(1.);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.);
+}
+
+#[test]
+fn test_gating_on_dendrite() {
+    let neuron = Neuron::new(5., 8., 0.).with_soma_rule(SomaRule::Max);
+    neuron.add_dendrite(5., 1.);
+    neuron.add_dendrite(5., 1.);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    let gate = Rc::new(NeuronicSensor::new());
+
+    neuron.create_dendritic_synapse(
+        0,
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_dendritic_synapse(
+        1,
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_dendritic_synapse(
+        0,
+        1.,
+        SynapticType::Gating,
+        Rc::clone(&gate) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.9);
+    s2.set_measure(0.6);
+    gate.set_measure(0.);
+
+    // The gate only closes its own dendrite
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.6);
+    assert_eq!(neuron.get_dendrite_measures(), vec![0., 0.6]);
+}
+
+#[test]
+fn test_modulatory_synapse() {
+    let learning_constant = 0.1;
+    let neuron = Neuron::new(1., 8., learning_constant);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let modulator = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.5);
+
+    // Without modulatory synapses learning is unscaled
+    neuron.run_cycle(ChargeCycle::Even);
+    assert_eq!(neuron.get_modulation(), None);
+
+    let delta = learning_constant * (8. - 4.) * 0.5;
+    cmp_f32(neuron.get_weights()[0], 4. + delta);
+
+    neuron.set_weights(&[4.]);
+    neuron.create_synapse(
+        2.,
+        SynapticType::Modulatory,
+        Rc::clone(&modulator) as Rc<dyn NeuronicInput>,
+    );
+
+    // Modulation scales learning, but not the measure
+    modulator.set_measure(0.25);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.5);
+    cmp_f32(neuron.get_modulation().unwrap(), 0.5);
+
+    let weights = neuron.get_weights();
+    cmp_f32(weights[0], 4. + (0.5 * delta));
+    cmp_f32(weights[1], 2.);
+
+    // No modulation means no learning
+    neuron.set_weights(&[4., 2.]);
+    modulator.set_measure(0.);
+    neuron.run_cycle(ChargeCycle::Even);
+    cmp_f32(neuron.get_weights()[0], 4.);
+}
+
+#[test]
+fn test_balanced_inhibitory_rule() {
+    let target_measure = 0.3;
+
+    // Excitatory synapses don't learn, so only inhibition changes
+    let neuron = Neuron::new(5., 8., 0.).with_inhibitory_rule(InhibitoryRule::Balanced {
+        target_measure,
+        balance_rate: 0.5,
+    });
+
+    let inhibitor = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        0.,
+        SynapticType::Inhibitory,
+        Rc::clone(&inhibitor) as Rc<dyn NeuronicInput>,
+    );
+
+    for measure in [0.9, 0.6, 0.3].iter() {
+        let sensor = Rc::new(NeuronicSensor::new());
+        sensor.set_measure(*measure);
+        neuron.create_synapse(
+            3.,
+            SynapticType::Excitatory,
+            Rc::clone(&sensor) as Rc<dyn NeuronicInput>,
+        );
+    }
+
+    inhibitor.set_measure(1.);
+
+    // Without inhibition the neuron fires above its target,
+    // so the inhibitory weight grows by 0.5 * 1.0 * (0.6 - 0.3)
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.6);
+    cmp_f32(neuron.get_weights()[0], 0.15);
+
+    let mut cycle = ChargeCycle::Odd;
+    for _ in 0..20 {
+        neuron.run_cycle(cycle);
+        cycle = cycle.next_cycle();
+    }
+
+    // Inhibition grows until it balances excitation at the target, then stops
+    let balanced_weight = neuron.get_weights()[0];
+    assert!(balanced_weight > 1. && balanced_weight <= 4.);
+    cmp_f32(neuron.run_cycle(cycle), target_measure);
+    cmp_f32(neuron.get_weights()[0], balanced_weight);
+
+    // Excitatory synapses never moved
+    assert_eq!(&neuron.get_weights()[1..], &[3., 3., 3.]);
+
+    // With too much inhibition the neuron falls silent,
+    // and inhibition shrinks until it fires again
+    neuron.set_weights(&[7., 3., 3., 3.]);
+    cycle = cycle.next_cycle();
+    cmp_f32(neuron.run_cycle(cycle), 0.);
+    cmp_f32(neuron.get_weights()[0], 7. - 0.15);
+
+    for _ in 0..30 {
+        cycle = cycle.next_cycle();
+        neuron.run_cycle(cycle);
+    }
+    cycle = cycle.next_cycle();
+    cmp_f32(neuron.run_cycle(cycle), target_measure);
+}
+
+#[test]
+fn test_balanced_rule_follows_schedule() {
+    let clock = Rc::new(Clock::new());
+
+    let neuron = Neuron::new(5., 8., 0.)
+        .with_inhibitory_rule(InhibitoryRule::Balanced {
+            target_measure: 0.3,
+            balance_rate: 0.5,
+        })
+        .with_learning_schedule(
+            LearningSchedule::Step {
+                every: 1,
+                factor: 0.5,
+            },
+            Rc::clone(&clock),
+        );
+
+    let inhibitor = Rc::new(NeuronicSensor::new());
+    let sensor = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        0.,
+        SynapticType::Inhibitory,
+        Rc::clone(&inhibitor) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&sensor) as Rc<dyn NeuronicInput>,
+    );
+    inhibitor.set_measure(1.);
+    sensor.set_measure(0.6);
+
+    // The rate is halved by the time of the second cycle,
+    // so the weight grows by 0.5 * 0.3, then 0.25 * 0.3
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.6);
+    cmp_f32(neuron.get_weights()[0], 0.15);
+
+    clock.advance();
+    neuron.set_weights(&[0., 6.]);
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.6);
+    cmp_f32(neuron.get_weights()[0], 0.075);
+}
+
+#[test]
+fn test_fixed_inhibitory_rule() {
+    let neuron = Neuron::new(1., 8., 0.1).with_inhibitory_rule(InhibitoryRule::Fixed);
+    assert_eq!(neuron.get_inhibitory_rule(), InhibitoryRule::Fixed);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        2.,
+        SynapticType::Inhibitory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.5);
+    s2.set_measure(0.2);
+
+    neuron.run_cycle(ChargeCycle::Even);
+
+    let weights = neuron.get_weights();
+    cmp_f32(weights[0], 4. + (0.1 * (8. - 4.) * 0.5));
+    cmp_f32(weights[1], 2.);
+}
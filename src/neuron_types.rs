@@ -0,0 +1,288 @@
+//! Neurons that compute a fixed function of their inputs rather
+//! than running the threshold walk.  None of these learn, so
+//! update_synapses does nothing, but they all live in the same graph
+//! as a regular Neuron and can feed into or read from one
+
+use crate::neuron::{ChargeCycle, InternalMeasure, Neuronic, NeuronicInput, SynapticType};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A presynaptic input as seen by one of these neurons
+struct WeightedInput {
+    weight: f32,
+    synaptic_type: SynapticType,
+    input: Rc<dyn NeuronicInput>,
+}
+
+impl WeightedInput {
+    fn get_measure(&self, cycle: ChargeCycle) -> f32 {
+        self.input.get_measure(cycle.prev_cycle())
+    }
+}
+
+/// Always emits the same measure, regardless of cycle
+pub struct BiasNeuron {
+    measure: f32,
+}
+
+impl BiasNeuron {
+    pub fn new(measure: f32) -> BiasNeuron {
+        // Make sure measure is only between 0 and 1
+        if !(0. ..=1.).contains(&measure) {
+            panic!(
+                "Measure {} out of bounds!  Measure must be between 0.0 and 1.0",
+                measure
+            );
+        }
+        BiasNeuron { measure }
+    }
+}
+
+impl NeuronicInput for BiasNeuron {
+    fn get_measure(&self, _cycle: ChargeCycle) -> f32 {
+        self.measure
+    }
+}
+
+impl Neuronic for BiasNeuron {
+    fn run_static_cycle(&self, _cycle: ChargeCycle) -> f32 {
+        self.measure
+    }
+
+    fn update_synapses(&self, _cycle: ChargeCycle) {}
+
+    fn clear(&self) {}
+
+    fn create_synapse(
+        &self,
+        _starting_weight: f32,
+        _synaptic_type: SynapticType,
+        _input: Rc<dyn NeuronicInput>,
+    ) {
+        panic!("A BiasNeuron can't have any synapses");
+    }
+}
+
+/// Copies the measure of its single input, delayed by one cycle
+/// like any other neuron.  The weight and type of the synapse are ignored
+pub struct RelayNeuron {
+    input: RefCell<Option<Rc<dyn NeuronicInput>>>,
+    internal_measure: RefCell<InternalMeasure>,
+}
+
+impl Default for RelayNeuron {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelayNeuron {
+    pub fn new() -> RelayNeuron {
+        RelayNeuron {
+            input: RefCell::new(None),
+            internal_measure: RefCell::new(InternalMeasure::new()),
+        }
+    }
+}
+
+impl NeuronicInput for RelayNeuron {
+    fn get_measure(&self, cycle: ChargeCycle) -> f32 {
+        self.internal_measure.borrow().get_measure(cycle)
+    }
+}
+
+impl Neuronic for RelayNeuron {
+    fn run_static_cycle(&self, cycle: ChargeCycle) -> f32 {
+        let measure = match self.input.borrow().as_ref() {
+            Some(input) => input.get_measure(cycle.prev_cycle()),
+            None => 0.0,
+        };
+
+        self.internal_measure
+            .borrow_mut()
+            .set_measure(cycle, measure);
+        measure
+    }
+
+    fn update_synapses(&self, _cycle: ChargeCycle) {}
+
+    fn clear(&self) {
+        self.internal_measure.borrow_mut().clear();
+    }
+
+    fn create_synapse(
+        &self,
+        _starting_weight: f32,
+        _synaptic_type: SynapticType,
+        input: Rc<dyn NeuronicInput>,
+    ) {
+        let mut current_input = self.input.borrow_mut();
+
+        if current_input.is_some() {
+            panic!("A RelayNeuron can only have a single synapse");
+        }
+        *current_input = Some(input);
+    }
+}
+
+/// Generates the shared pieces of the pooling neurons below, which
+/// only differ in how they combine their inputs
+macro_rules! pooling_neuron {
+    ($name:ident) => {
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $name {
+            pub fn new() -> $name {
+                $name {
+                    inputs: RefCell::new(Vec::new()),
+                    internal_measure: RefCell::new(InternalMeasure::new()),
+                }
+            }
+
+            pub fn get_synapse_count(&self) -> usize {
+                self.inputs.borrow().len()
+            }
+        }
+
+        impl NeuronicInput for $name {
+            fn get_measure(&self, cycle: ChargeCycle) -> f32 {
+                self.internal_measure.borrow().get_measure(cycle)
+            }
+        }
+
+        impl Neuronic for $name {
+            fn run_static_cycle(&self, cycle: ChargeCycle) -> f32 {
+                let measure = self.pool(cycle);
+
+                self.internal_measure
+                    .borrow_mut()
+                    .set_measure(cycle, measure);
+                measure
+            }
+
+            fn update_synapses(&self, _cycle: ChargeCycle) {}
+
+            fn clear(&self) {
+                self.internal_measure.borrow_mut().clear();
+            }
+
+            fn create_synapse(
+                &self,
+                starting_weight: f32,
+                synaptic_type: SynapticType,
+                input: Rc<dyn NeuronicInput>,
+            ) {
+                self.inputs.borrow_mut().push(WeightedInput {
+                    weight: starting_weight,
+                    synaptic_type,
+                    input,
+                });
+            }
+
+            fn get_synapse_inputs(&self) -> Vec<(Rc<dyn NeuronicInput>, SynapticType)> {
+                self.inputs
+                    .borrow()
+                    .iter()
+                    .map(|input| (Rc::clone(&input.input), input.synaptic_type))
+                    .collect()
+            }
+        }
+    };
+}
+
+/// Emits the largest measure among its inputs.
+/// Weights and synaptic types are ignored
+pub struct MaxNeuron {
+    inputs: RefCell<Vec<WeightedInput>>,
+    internal_measure: RefCell<InternalMeasure>,
+}
+
+pooling_neuron!(MaxNeuron);
+
+impl MaxNeuron {
+    fn pool(&self, cycle: ChargeCycle) -> f32 {
+        self.inputs
+            .borrow()
+            .iter()
+            .map(|input| input.get_measure(cycle))
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Emits the smallest measure among its inputs, so it only
+/// emits a high measure if all of its inputs are high.
+/// Weights and synaptic types are ignored
+pub struct MinNeuron {
+    inputs: RefCell<Vec<WeightedInput>>,
+    internal_measure: RefCell<InternalMeasure>,
+}
+
+pooling_neuron!(MinNeuron);
+
+impl MinNeuron {
+    fn pool(&self, cycle: ChargeCycle) -> f32 {
+        let inputs = self.inputs.borrow();
+
+        if inputs.is_empty() {
+            return 0.0;
+        }
+
+        inputs
+            .iter()
+            .map(|input| input.get_measure(cycle))
+            .fold(1.0, f32::min)
+    }
+}
+
+/// Emits the weighted average of its inputs' measures,
+/// where inhibitory inputs count against the average.
+/// Gating and shunting inputs scale the average the same way they
+/// scale impulses in a Neuron, and modulatory inputs are ignored
+/// since nothing here learns.
+/// The result is clamped between 0 and 1 so it remains a valid measure
+pub struct LinearNeuron {
+    inputs: RefCell<Vec<WeightedInput>>,
+    internal_measure: RefCell<InternalMeasure>,
+}
+
+pooling_neuron!(LinearNeuron);
+
+impl LinearNeuron {
+    fn pool(&self, cycle: ChargeCycle) -> f32 {
+        let mut weighted_sum = 0.0;
+        let mut total_weight = 0.0;
+        let mut gate = 1.0;
+        let mut shunt = 0.0;
+
+        for input in self.inputs.borrow().iter() {
+            let measure = input.get_measure(cycle);
+
+            match input.synaptic_type {
+                SynapticType::Excitatory => {
+                    weighted_sum += input.weight * measure;
+                    total_weight += input.weight;
+                }
+                SynapticType::Inhibitory => {
+                    weighted_sum -= input.weight * measure;
+                    total_weight += input.weight;
+                }
+                SynapticType::Gating => gate *= input.weight * measure,
+                SynapticType::Shunting => shunt += input.weight * measure,
+                SynapticType::Modulatory => (),
+            }
+        }
+
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+
+        (gate * weighted_sum / (total_weight * (1.0 + shunt))).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod neuron_types_tests;
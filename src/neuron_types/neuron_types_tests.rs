@@ -0,0 +1,174 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::neuron_types::{BiasNeuron, LinearNeuron, MaxNeuron, MinNeuron, RelayNeuron};
+use crate::test_utils::cmp_f32;
+use std::rc::Rc;
+
+#[test]
+fn test_bias_neuron() {
+    let bias = Rc::new(BiasNeuron::new(0.7));
+
+    cmp_f32(bias.get_measure(ChargeCycle::Even), 0.7);
+    cmp_f32(bias.get_measure(ChargeCycle::Odd), 0.7);
+
+    cmp_f32(bias.run_cycle(ChargeCycle::Even), 0.7);
+    bias.clear();
+    cmp_f32(bias.get_measure(ChargeCycle::Odd), 0.7);
+
+    // A bias neuron can drive a regular neuron
+    let neuron = Neuron::new(5., 8., 0.1);
+    neuron.create_synapse(6., SynapticType::Excitatory, bias as Rc<dyn NeuronicInput>);
+
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.7);
+}
+
+#[test]
+#[should_panic]
+fn test_bias_neuron_synapse() {
+    let bias = BiasNeuron::new(0.7);
+
+    bias.create_synapse(1., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+}
+
+#[test]
+fn test_relay_neuron() {
+    let relay = RelayNeuron::new();
+
+    cmp_f32(relay.run_static_cycle(ChargeCycle::Even), 0.0);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    relay.create_synapse(
+        3.,
+        SynapticType::Inhibitory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.4);
+    relay.run_cycle(ChargeCycle::Odd);
+
+    cmp_f32(relay.get_measure(ChargeCycle::Odd), 0.4);
+    cmp_f32(relay.get_measure(ChargeCycle::Even), 0.0);
+
+    relay.clear();
+    cmp_f32(relay.get_measure(ChargeCycle::Odd), 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_relay_neuron_single_synapse() {
+    let relay = RelayNeuron::new();
+
+    relay.create_synapse(1., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+    relay.create_synapse(1., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+}
+
+#[test]
+fn test_max_min_neurons() {
+    let max = MaxNeuron::new();
+    let min = MinNeuron::new();
+
+    // No inputs means no measure
+    cmp_f32(max.run_static_cycle(ChargeCycle::Even), 0.0);
+    cmp_f32(min.run_static_cycle(ChargeCycle::Even), 0.0);
+
+    let measures = [0.3, 0.9, 0.5];
+
+    for measure in measures.iter() {
+        let sensor = Rc::new(NeuronicSensor::new());
+        sensor.set_measure(*measure);
+
+        max.create_synapse(
+            1.,
+            SynapticType::Excitatory,
+            Rc::clone(&sensor) as Rc<dyn NeuronicInput>,
+        );
+        min.create_synapse(
+            1.,
+            SynapticType::Excitatory,
+            sensor as Rc<dyn NeuronicInput>,
+        );
+    }
+
+    assert_eq!(max.get_synapse_count(), 3);
+    assert_eq!(min.get_synapse_count(), 3);
+
+    cmp_f32(max.run_cycle(ChargeCycle::Odd), 0.9);
+    cmp_f32(min.run_cycle(ChargeCycle::Odd), 0.3);
+
+    cmp_f32(max.get_measure(ChargeCycle::Odd), 0.9);
+    cmp_f32(min.get_measure(ChargeCycle::Odd), 0.3);
+    cmp_f32(max.get_measure(ChargeCycle::Even), 0.0);
+    cmp_f32(min.get_measure(ChargeCycle::Even), 0.0);
+}
+
+#[test]
+fn test_linear_neuron() {
+    let linear = LinearNeuron::new();
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    let s3 = Rc::new(NeuronicSensor::new());
+
+    linear.create_synapse(
+        1.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    linear.create_synapse(
+        3.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+    linear.create_synapse(
+        1.,
+        SynapticType::Inhibitory,
+        Rc::clone(&s3) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.8);
+    s2.set_measure(0.4);
+    s3.set_measure(0.5);
+
+    cmp_f32(
+        linear.run_static_cycle(ChargeCycle::Even),
+        ((1. * 0.8) + (3. * 0.4) - (1. * 0.5)) / 5.,
+    );
+
+    // Inhibition can only pull the measure down to 0
+    s1.set_measure(0.0);
+    s2.set_measure(0.0);
+    s3.set_measure(1.0);
+
+    cmp_f32(linear.run_static_cycle(ChargeCycle::Odd), 0.0);
+}
+
+#[test]
+fn test_linear_neuron_gating_and_shunting() {
+    let linear = LinearNeuron::new();
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let gate = Rc::new(NeuronicSensor::new());
+    let shunt = Rc::new(NeuronicSensor::new());
+
+    linear.create_synapse(
+        1.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    linear.create_synapse(
+        1.,
+        SynapticType::Gating,
+        Rc::clone(&gate) as Rc<dyn NeuronicInput>,
+    );
+    linear.create_synapse(
+        1.,
+        SynapticType::Shunting,
+        Rc::clone(&shunt) as Rc<dyn NeuronicInput>,
+    );
+    linear.create_synapse(1., SynapticType::Modulatory, Rc::new(NeuronicSensor::new()));
+
+    s1.set_measure(0.8);
+    gate.set_measure(0.5);
+    shunt.set_measure(1.);
+
+    cmp_f32(linear.run_static_cycle(ChargeCycle::Even), 0.8 * 0.5 / 2.);
+}
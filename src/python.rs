@@ -0,0 +1,327 @@
+//! Python bindings, enabled with the `python` feature.
+//! Everything here is a thin wrapper around the rust types,
+//! the only real logic being that panics on bad input are
+//! turned into python exceptions before they can happen.
+//! Neurons are built out of Rc and RefCell, so none of these
+//! classes can be sent between python threads
+
+use crate::network::Network;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use std::rc::Rc;
+
+#[pyclass(name = "ChargeCycle", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum PyChargeCycle {
+    Even,
+    Odd,
+}
+
+impl From<PyChargeCycle> for ChargeCycle {
+    fn from(cycle: PyChargeCycle) -> ChargeCycle {
+        match cycle {
+            PyChargeCycle::Even => ChargeCycle::Even,
+            PyChargeCycle::Odd => ChargeCycle::Odd,
+        }
+    }
+}
+
+impl From<ChargeCycle> for PyChargeCycle {
+    fn from(cycle: ChargeCycle) -> PyChargeCycle {
+        match cycle {
+            ChargeCycle::Even => PyChargeCycle::Even,
+            ChargeCycle::Odd => PyChargeCycle::Odd,
+        }
+    }
+}
+
+#[pyclass(name = "SynapticType", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum PySynapticType {
+    Excitatory,
+    Inhibitory,
+    Gating,
+    Shunting,
+    Modulatory,
+}
+
+impl From<PySynapticType> for SynapticType {
+    fn from(synaptic_type: PySynapticType) -> SynapticType {
+        match synaptic_type {
+            PySynapticType::Excitatory => SynapticType::Excitatory,
+            PySynapticType::Inhibitory => SynapticType::Inhibitory,
+            PySynapticType::Gating => SynapticType::Gating,
+            PySynapticType::Shunting => SynapticType::Shunting,
+            PySynapticType::Modulatory => SynapticType::Modulatory,
+        }
+    }
+}
+
+fn check_measure(measure: f32) -> PyResult<()> {
+    if !(0. ..=1.).contains(&measure) {
+        return Err(PyValueError::new_err(format!(
+            "Measure {} out of bounds!  Measure must be between 0.0 and 1.0",
+            measure
+        )));
+    }
+    Ok(())
+}
+
+fn check_measures(sensor_count: usize, measures: &[f32]) -> PyResult<()> {
+    if measures.len() != sensor_count {
+        return Err(PyValueError::new_err(format!(
+            "Got {} measures for {} sensors",
+            measures.len(),
+            sensor_count
+        )));
+    }
+
+    for measure in measures.iter() {
+        check_measure(*measure)?;
+    }
+    Ok(())
+}
+
+fn check_weights(neuron: &Neuron, weights: &[f32]) -> PyResult<()> {
+    if weights.len() != neuron.get_synapse_count() {
+        return Err(PyValueError::new_err(format!(
+            "Got {} weights for a neuron with {} synapses",
+            weights.len(),
+            neuron.get_synapse_count()
+        )));
+    }
+    Ok(())
+}
+
+#[pyclass(name = "NeuronicSensor", unsendable)]
+pub struct PyNeuronicSensor {
+    inner: Rc<NeuronicSensor>,
+}
+
+#[pymethods]
+impl PyNeuronicSensor {
+    #[new]
+    fn new() -> PyNeuronicSensor {
+        PyNeuronicSensor {
+            inner: Rc::new(NeuronicSensor::new()),
+        }
+    }
+
+    fn set_measure(&self, measure: f32) -> PyResult<()> {
+        check_measure(measure)?;
+        self.inner.set_measure(measure);
+        Ok(())
+    }
+
+    fn get_measure(&self) -> f32 {
+        self.inner.get_measure(ChargeCycle::Even)
+    }
+}
+
+#[pyclass(name = "Neuron", unsendable)]
+pub struct PyNeuron {
+    inner: Rc<Neuron>,
+}
+
+#[pymethods]
+impl PyNeuron {
+    #[new]
+    fn new(fire_threshold: f32, max_synapse_weight: f32, learning_constant: f32) -> PyNeuron {
+        PyNeuron {
+            inner: Rc::new(Neuron::new(
+                fire_threshold,
+                max_synapse_weight,
+                learning_constant,
+            )),
+        }
+    }
+
+    /// `input` may be either a Neuron or a NeuronicSensor
+    fn create_synapse(
+        &self,
+        starting_weight: f32,
+        synaptic_type: PySynapticType,
+        input: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let input: Rc<dyn NeuronicInput> = if let Ok(neuron) = input.cast::<PyNeuron>() {
+            Rc::clone(&neuron.borrow().inner) as Rc<dyn NeuronicInput>
+        } else if let Ok(sensor) = input.cast::<PyNeuronicSensor>() {
+            Rc::clone(&sensor.borrow().inner) as Rc<dyn NeuronicInput>
+        } else {
+            return Err(PyTypeError::new_err(
+                "Synapse input must be a Neuron or a NeuronicSensor",
+            ));
+        };
+
+        self.inner
+            .create_synapse(starting_weight, synaptic_type.into(), input);
+        Ok(())
+    }
+
+    fn run_cycle(&self, cycle: PyChargeCycle) -> f32 {
+        self.inner.run_cycle(cycle.into())
+    }
+
+    fn run_static_cycle(&self, cycle: PyChargeCycle) -> f32 {
+        self.inner.run_static_cycle(cycle.into())
+    }
+
+    fn update_synapses(&self, cycle: PyChargeCycle) {
+        self.inner.update_synapses(cycle.into())
+    }
+
+    fn clear(&self) {
+        self.inner.clear()
+    }
+
+    fn get_measure(&self, cycle: PyChargeCycle) -> f32 {
+        self.inner.get_measure(cycle.into())
+    }
+
+    fn get_synapse_count(&self) -> usize {
+        self.inner.get_synapse_count()
+    }
+
+    fn get_weights<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_vec(py, self.inner.get_weights())
+    }
+
+    fn set_weights(&self, weights: PyReadonlyArray1<'_, f32>) -> PyResult<()> {
+        let weights = weights.as_slice()?;
+        check_weights(&self.inner, weights)?;
+        self.inner.set_weights(weights);
+        Ok(())
+    }
+}
+
+/// Wraps a rust Network, and additionally remembers which neurons
+/// and sensors were added so measures and weights can be moved
+/// in and out as numpy arrays
+#[pyclass(name = "Network", unsendable)]
+pub struct PyNetwork {
+    network: Network,
+    neurons: Vec<Rc<Neuron>>,
+    sensors: Vec<Rc<NeuronicSensor>>,
+}
+
+impl PyNetwork {
+    fn get_neuron(&self, index: usize) -> PyResult<&Rc<Neuron>> {
+        self.neurons
+            .get(index)
+            .ok_or_else(|| PyIndexError::new_err(format!("No neuron at index {}", index)))
+    }
+}
+
+#[pymethods]
+impl PyNetwork {
+    #[new]
+    fn new() -> PyNetwork {
+        PyNetwork {
+            network: Network::new(),
+            neurons: Vec::new(),
+            sensors: Vec::new(),
+        }
+    }
+
+    /// Adds a neuron and returns its index for the bulk getters and setters
+    fn add_neuron(&mut self, neuron: PyRef<'_, PyNeuron>) -> usize {
+        self.network
+            .add_neuron(Rc::clone(&neuron.inner) as Rc<dyn Neuronic>);
+        self.neurons.push(Rc::clone(&neuron.inner));
+
+        self.neurons.len() - 1
+    }
+
+    /// Adds a sensor and returns its index for the bulk getters and setters
+    fn add_sensor(&mut self, sensor: PyRef<'_, PyNeuronicSensor>) -> usize {
+        self.sensors.push(Rc::clone(&sensor.inner));
+
+        self.sensors.len() - 1
+    }
+
+    fn get_cycle(&self) -> PyChargeCycle {
+        self.network.get_cycle().into()
+    }
+
+    fn run_cycle(&mut self) {
+        self.network.run_cycle();
+    }
+
+    fn run_static_cycle(&mut self) {
+        self.network.run_static_cycle();
+    }
+
+    fn clear(&mut self) {
+        self.network.clear();
+    }
+
+    /// Measures of every neuron from the most recently run cycle
+    fn get_measures<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        let cycle = self.network.get_cycle().prev_cycle();
+
+        PyArray1::from_vec(
+            py,
+            self.neurons
+                .iter()
+                .map(|neuron| neuron.get_measure(cycle))
+                .collect(),
+        )
+    }
+
+    fn get_sensor_measures<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f32>> {
+        PyArray1::from_vec(
+            py,
+            self.sensors
+                .iter()
+                .map(|sensor| sensor.get_measure(ChargeCycle::Even))
+                .collect(),
+        )
+    }
+
+    /// Sets every sensor at once.  Nothing is set unless all
+    /// measures are valid
+    fn set_sensor_measures(&self, measures: PyReadonlyArray1<'_, f32>) -> PyResult<()> {
+        let measures = measures.as_slice()?;
+        check_measures(self.sensors.len(), measures)?;
+
+        for (sensor, measure) in self.sensors.iter().zip(measures.iter()) {
+            sensor.set_measure(*measure);
+        }
+        Ok(())
+    }
+
+    fn get_weights<'py>(
+        &self,
+        py: Python<'py>,
+        neuron_index: usize,
+    ) -> PyResult<Bound<'py, PyArray1<f32>>> {
+        Ok(PyArray1::from_vec(
+            py,
+            self.get_neuron(neuron_index)?.get_weights(),
+        ))
+    }
+
+    fn set_weights(&self, neuron_index: usize, weights: PyReadonlyArray1<'_, f32>) -> PyResult<()> {
+        let neuron = self.get_neuron(neuron_index)?;
+        let weights = weights.as_slice()?;
+
+        check_weights(neuron, weights)?;
+        neuron.set_weights(weights);
+        Ok(())
+    }
+}
+
+#[pymodule]
+fn aesir3(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyChargeCycle>()?;
+    m.add_class::<PySynapticType>()?;
+    m.add_class::<PyNeuronicSensor>()?;
+    m.add_class::<PyNeuron>()?;
+    m.add_class::<PyNetwork>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod python_tests;
@@ -0,0 +1,49 @@
+use crate::neuron::{Neuron, Neuronic, NeuronicSensor, SynapticType};
+use crate::python::{check_measures, check_weights};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::rc::Rc;
+
+/// Runs f with an embedded interpreter, so the errors can be
+/// checked the way python would see them
+fn with_python<F: FnOnce(Python<'_>)>(f: F) {
+    Python::initialize();
+    Python::attach(f);
+}
+
+#[test]
+fn test_check_measures() {
+    with_python(|py| {
+        assert!(check_measures(2, &[0., 1.]).is_ok());
+
+        let wrong_length = check_measures(2, &[0.5]).unwrap_err();
+        assert!(wrong_length.is_instance_of::<PyValueError>(py));
+        assert_eq!(
+            wrong_length.value(py).to_string(),
+            "Got 1 measures for 2 sensors"
+        );
+
+        let out_of_bounds = check_measures(2, &[0.5, 1.5]).unwrap_err();
+        assert!(out_of_bounds.is_instance_of::<PyValueError>(py));
+
+        let nan = check_measures(1, &[f32::NAN]).unwrap_err();
+        assert!(nan.is_instance_of::<PyValueError>(py));
+    });
+}
+
+#[test]
+fn test_check_weights() {
+    with_python(|py| {
+        let neuron = Neuron::new(10., 8., 0.1);
+        neuron.create_synapse(2., SynapticType::Excitatory, Rc::new(NeuronicSensor::new()));
+
+        assert!(check_weights(&neuron, &[3.]).is_ok());
+
+        let wrong_length = check_weights(&neuron, &[3., 4.]).unwrap_err();
+        assert!(wrong_length.is_instance_of::<PyValueError>(py));
+        assert_eq!(
+            wrong_length.value(py).to_string(),
+            "Got 2 weights for a neuron with 1 synapses"
+        );
+    });
+}
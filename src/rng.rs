@@ -0,0 +1,77 @@
+/// Small seeded random number generator, SplitMix64, so that
+/// randomly built networks are identical for a given seed without
+/// depending on an outside crate.  Not suitable for cryptography
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        // The top 24 bits fill an f32's mantissa exactly
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform in [min, max)
+    pub fn uniform(&mut self, min: f32, max: f32) -> f32 {
+        min + ((max - min) * self.next_f32())
+    }
+
+    /// True with the given probability
+    pub fn chance(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Uniform in [0, n).  n must be positive
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            panic!("Can't pick a number below 0");
+        }
+
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// k distinct indices in [0, n), in random order, using a partial
+    /// Fisher-Yates shuffle.  k must not be larger than n
+    pub fn sample_indices(&mut self, n: usize, k: usize) -> Vec<usize> {
+        if k > n {
+            panic!("Can't pick {} distinct indices out of {}", k, n);
+        }
+
+        let mut indices: Vec<usize> = (0..n).collect();
+        for i in 0..k {
+            let j = i + self.below(n - i);
+            indices.swap(i, j);
+        }
+
+        indices.truncate(k);
+        indices
+    }
+
+    /// Normally distributed, using the Box-Muller transform
+    pub fn normal(&mut self, mean: f32, std_dev: f32) -> f32 {
+        // 1 - u is in (0, 1], so the log is finite
+        let u1 = 1.0 - self.next_f32();
+        let u2 = self.next_f32();
+
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos();
+        mean + (std_dev * z)
+    }
+}
+
+#[cfg(test)]
+mod rng_tests;
@@ -0,0 +1,75 @@
+use crate::rng::Rng;
+
+#[test]
+fn test_seeded() {
+    let mut r1 = Rng::new(42);
+    let mut r2 = Rng::new(42);
+    let mut r3 = Rng::new(43);
+
+    let s1: Vec<u64> = (0..10).map(|_| r1.next_u64()).collect();
+    let s2: Vec<u64> = (0..10).map(|_| r2.next_u64()).collect();
+    let s3: Vec<u64> = (0..10).map(|_| r3.next_u64()).collect();
+
+    assert_eq!(s1, s2);
+    assert_ne!(s1, s3);
+}
+
+#[test]
+fn test_known_sequence() {
+    // Reference values of SplitMix64 seeded with 0
+    let mut rng = Rng::new(0);
+
+    assert_eq!(rng.next_u64(), 0xE220_A839_7B1D_CDAF);
+    assert_eq!(rng.next_u64(), 0x6E78_9E6A_A1B9_65F4);
+}
+
+#[test]
+fn test_uniform() {
+    let mut rng = Rng::new(7);
+    let samples: Vec<f32> = (0..10000).map(|_| rng.uniform(2., 4.)).collect();
+
+    assert!(samples.iter().all(|x| (2. ..4.).contains(x)));
+
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    assert!((mean - 3.).abs() < 0.05, "mean was {}", mean);
+}
+
+#[test]
+fn test_normal() {
+    let mut rng = Rng::new(7);
+    let samples: Vec<f32> = (0..10000).map(|_| rng.normal(1., 2.)).collect();
+
+    let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+    let variance =
+        samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f32>() / samples.len() as f32;
+
+    assert!((mean - 1.).abs() < 0.1, "mean was {}", mean);
+    assert!(
+        (variance.sqrt() - 2.).abs() < 0.1,
+        "std dev was {}",
+        variance.sqrt()
+    );
+}
+
+#[test]
+fn test_sample_indices() {
+    let mut rng = Rng::new(1);
+
+    let mut indices = rng.sample_indices(10, 4);
+    assert_eq!(indices.len(), 4);
+
+    indices.sort_unstable();
+    indices.dedup();
+    assert_eq!(indices.len(), 4);
+    assert!(indices.iter().all(|i| *i < 10));
+
+    let mut all = rng.sample_indices(5, 5);
+    all.sort_unstable();
+    assert_eq!(all, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_too_many_indices() {
+    Rng::new(1).sample_indices(3, 4);
+}
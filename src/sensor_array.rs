@@ -0,0 +1,125 @@
+use crate::neuron::{ChargeCycle, NeuronicInput};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Many sensors stored together, so a whole input, like an image,
+/// can be set in one call.  Neurons connect to single channels
+/// through get_channel, which shares the array's storage
+pub struct SensorArray {
+    measures: RefCell<Vec<f32>>,
+}
+
+/// A single channel of a SensorArray
+pub struct SensorChannel {
+    array: Rc<SensorArray>,
+    index: usize,
+}
+
+impl SensorArray {
+    /// Every channel starts at 0
+    pub fn new(channels: usize) -> SensorArray {
+        SensorArray {
+            measures: RefCell::new(vec![0.0; channels]),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.measures.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.measures.borrow().is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> f32 {
+        self.check_index(index);
+        self.measures.borrow()[index]
+    }
+
+    pub fn set(&self, index: usize, measure: f32) {
+        self.check_index(index);
+        check_measure(measure);
+
+        self.measures.borrow_mut()[index] = measure;
+    }
+
+    pub fn get_all(&self) -> Vec<f32> {
+        self.measures.borrow().clone()
+    }
+
+    /// Sets every channel at once.  Every measure is checked
+    /// before any are set, so a bad input leaves the array as it was
+    pub fn set_all(&self, measures: &[f32]) {
+        if measures.len() != self.len() {
+            panic!(
+                "Got {} measures for a sensor array with {} channels",
+                measures.len(),
+                self.len()
+            );
+        }
+
+        if let Some(measure) = measures.iter().find(|m| !(0. ..=1.).contains(*m)) {
+            check_measure(*measure);
+        }
+
+        self.measures.borrow_mut().copy_from_slice(measures);
+    }
+
+    /// Sets every channel to 0
+    pub fn clear(&self) {
+        for measure in self.measures.borrow_mut().iter_mut() {
+            *measure = 0.0;
+        }
+    }
+
+    pub fn get_channel(self: &Rc<Self>, index: usize) -> Rc<SensorChannel> {
+        self.check_index(index);
+
+        Rc::new(SensorChannel {
+            array: Rc::clone(self),
+            index,
+        })
+    }
+
+    /// Every channel as an input, in order, for example to pass to a Connector
+    pub fn get_inputs(self: &Rc<Self>) -> Vec<Rc<dyn NeuronicInput>> {
+        (0..self.len())
+            .map(|index| self.get_channel(index) as Rc<dyn NeuronicInput>)
+            .collect()
+    }
+
+    fn check_index(&self, index: usize) {
+        if index >= self.len() {
+            panic!(
+                "No channel at index {}, sensor array has {} channels",
+                index,
+                self.len()
+            );
+        }
+    }
+}
+
+impl SensorChannel {
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+}
+
+impl NeuronicInput for SensorChannel {
+    fn get_measure(&self, _cycle: ChargeCycle) -> f32 {
+        self.array.measures.borrow()[self.index]
+    }
+}
+
+fn check_measure(measure: f32) {
+    // Make sure measure is only between 0 and 1
+    if !(0. ..=1.).contains(&measure) {
+        panic!(
+            "Measure {} out of bounds!  Measure must be between 0.0 and 1.0",
+            measure
+        );
+    }
+}
+
+#[cfg(test)]
+mod sensor_array_tests;
@@ -0,0 +1,68 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, SynapticType};
+use crate::sensor_array::SensorArray;
+use crate::test_utils::cmp_f32;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+#[test]
+fn test_set_all() {
+    let array = Rc::new(SensorArray::new(4));
+    assert_eq!(array.len(), 4);
+    assert_eq!(array.get_all(), vec![0.; 4]);
+
+    array.set_all(&[0.1, 0.2, 0.3, 0.4]);
+    assert_eq!(array.get_all(), vec![0.1, 0.2, 0.3, 0.4]);
+
+    array.set(2, 0.9);
+    cmp_f32(array.get(2), 0.9);
+
+    // Channels see the array's storage
+    let channel = array.get_channel(2);
+    assert_eq!(channel.get_index(), 2);
+    cmp_f32(channel.get_measure(ChargeCycle::Even), 0.9);
+
+    array.set_all(&[0., 0., 0.5, 0.]);
+    cmp_f32(channel.get_measure(ChargeCycle::Odd), 0.5);
+
+    array.clear();
+    assert_eq!(array.get_all(), vec![0.; 4]);
+}
+
+#[test]
+fn test_set_all_validates_first() {
+    let array = SensorArray::new(3);
+    array.set_all(&[0.1, 0.2, 0.3]);
+
+    // The bad measure is last, but nothing is set
+    let result = catch_unwind(AssertUnwindSafe(|| array.set_all(&[0.5, 0.5, 1.5])));
+    assert!(result.is_err());
+    assert_eq!(array.get_all(), vec![0.1, 0.2, 0.3]);
+}
+
+#[test]
+#[should_panic]
+fn test_set_all_length_mismatch() {
+    SensorArray::new(3).set_all(&[0.5, 0.5]);
+}
+
+#[test]
+#[should_panic]
+fn test_channel_out_of_bounds() {
+    Rc::new(SensorArray::new(3)).get_channel(3);
+}
+
+#[test]
+fn test_drives_neuron() {
+    let array = Rc::new(SensorArray::new(3));
+    let neuron = Neuron::new(10., 8., 0.1);
+
+    for input in array.get_inputs() {
+        neuron.create_synapse(6., SynapticType::Excitatory, input);
+    }
+
+    array.set_all(&[0.9, 0.6, 0.3]);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), 0.6);
+
+    array.set_all(&[0.2, 0.4, 0.8]);
+    cmp_f32(neuron.run_static_cycle(ChargeCycle::Odd), 0.4);
+}
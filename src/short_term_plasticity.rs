@@ -0,0 +1,90 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Transient changes to a synapse's efficacy, which multiplies the
+/// learned weight whenever the synapse generates an impulse.  Efficacy
+/// rests at 1, is pushed away from 1 by every impulse in proportion
+/// to the impulse's measure, and recovers back towards 1 every cycle
+/// Build it with depression or facilitation, which check the parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ShortTermPlasticity {
+    /// Each impulse uses up depletion * measure of the remaining
+    /// efficacy, so sustained high measures weaken the synapse
+    Depression { depletion: f32, recovery: f32 },
+    /// Each impulse moves efficacy facilitation * measure of the way
+    /// towards max_efficacy, so sustained high measures strengthen the synapse
+    Facilitation {
+        facilitation: f32,
+        max_efficacy: f32,
+        recovery: f32,
+    },
+}
+
+impl ShortTermPlasticity {
+    /// depletion and recovery must be in [0, 1]
+    pub fn depression(depletion: f32, recovery: f32) -> ShortTermPlasticity {
+        check_rate("Depletion", depletion);
+        check_rate("Recovery", recovery);
+
+        ShortTermPlasticity::Depression {
+            depletion,
+            recovery,
+        }
+    }
+
+    /// facilitation and recovery must be in [0, 1],
+    /// and max_efficacy must be at least 1
+    pub fn facilitation(
+        facilitation: f32,
+        max_efficacy: f32,
+        recovery: f32,
+    ) -> ShortTermPlasticity {
+        check_rate("Facilitation", facilitation);
+        check_rate("Recovery", recovery);
+
+        if max_efficacy < 1. || max_efficacy.is_nan() {
+            panic!(
+                "Max efficacy {} out of bounds!  Must be at least 1.0",
+                max_efficacy
+            );
+        }
+
+        ShortTermPlasticity::Facilitation {
+            facilitation,
+            max_efficacy,
+            recovery,
+        }
+    }
+
+    /// Efficacy for the next cycle, after an impulse with
+    /// the given measure was generated at the current efficacy
+    pub fn next_efficacy(&self, efficacy: f32, measure: f32) -> f32 {
+        match *self {
+            ShortTermPlasticity::Depression {
+                depletion,
+                recovery,
+            } => {
+                let efficacy = efficacy - (depletion * measure * efficacy);
+                (efficacy + (recovery * (1.0 - efficacy))).clamp(0.0, 1.0)
+            }
+            ShortTermPlasticity::Facilitation {
+                facilitation,
+                max_efficacy,
+                recovery,
+            } => {
+                let efficacy = efficacy + (facilitation * measure * (max_efficacy - efficacy));
+                (efficacy + (recovery * (1.0 - efficacy))).clamp(1.0, max_efficacy.max(1.0))
+            }
+        }
+    }
+}
+
+fn check_rate(name: &str, rate: f32) {
+    if !(0. ..=1.).contains(&rate) {
+        panic!("{} {} out of bounds!  Must be in [0.0, 1.0]", name, rate);
+    }
+}
+
+#[cfg(test)]
+mod short_term_plasticity_tests;
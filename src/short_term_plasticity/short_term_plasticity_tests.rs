@@ -0,0 +1,163 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::short_term_plasticity::ShortTermPlasticity;
+use crate::test_utils::cmp_f32;
+use std::rc::Rc;
+
+#[test]
+fn test_depression() {
+    let depression = ShortTermPlasticity::Depression {
+        depletion: 0.5,
+        recovery: 0.2,
+    };
+
+    // Depleted by half, then recovers a fifth of the way back
+    cmp_f32(depression.next_efficacy(1.0, 1.0), 0.5 + (0.2 * 0.5));
+
+    // Low measures barely deplete
+    cmp_f32(depression.next_efficacy(1.0, 0.0), 1.0);
+
+    // With no input the efficacy recovers
+    cmp_f32(depression.next_efficacy(0.5, 0.0), 0.5 + (0.2 * 0.5));
+}
+
+#[test]
+fn test_facilitation() {
+    let facilitation = ShortTermPlasticity::Facilitation {
+        facilitation: 0.5,
+        max_efficacy: 2.0,
+        recovery: 0.2,
+    };
+
+    cmp_f32(facilitation.next_efficacy(1.0, 1.0), 1.5 - (0.2 * 0.5));
+    cmp_f32(facilitation.next_efficacy(1.5, 0.0), 1.5 - (0.2 * 0.5));
+
+    // Never passes the max
+    cmp_f32(facilitation.next_efficacy(2.0, 1.0), 2.0 - (0.2 * 1.0));
+}
+
+#[test]
+fn test_constructors() {
+    assert_eq!(
+        ShortTermPlasticity::depression(0.5, 0.2),
+        ShortTermPlasticity::Depression {
+            depletion: 0.5,
+            recovery: 0.2,
+        }
+    );
+    assert_eq!(
+        ShortTermPlasticity::facilitation(0.5, 2.0, 0.2),
+        ShortTermPlasticity::Facilitation {
+            facilitation: 0.5,
+            max_efficacy: 2.0,
+            recovery: 0.2,
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_depletion_out_of_bounds() {
+    ShortTermPlasticity::depression(1.5, 0.2);
+}
+
+#[test]
+#[should_panic]
+fn test_depression_recovery_out_of_bounds() {
+    ShortTermPlasticity::depression(0.5, -0.1);
+}
+
+#[test]
+#[should_panic]
+fn test_facilitation_out_of_bounds() {
+    ShortTermPlasticity::facilitation(-0.5, 2.0, 0.2);
+}
+
+#[test]
+#[should_panic]
+fn test_facilitation_recovery_out_of_bounds() {
+    ShortTermPlasticity::facilitation(0.5, 2.0, 1.5);
+}
+
+#[test]
+#[should_panic]
+fn test_max_efficacy_below_one() {
+    ShortTermPlasticity::facilitation(0.5, 0.8, 0.2);
+}
+
+#[test]
+fn test_neuron_adapts_to_sustained_input() {
+    let depression = ShortTermPlasticity::Depression {
+        depletion: 0.5,
+        recovery: 0.1,
+    };
+
+    // Learning is turned off so only short-term dynamics change the weight
+    let neuron = Neuron::new(5., 8., 0.).with_short_term_plasticity(depression);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+
+    // The first impulse is at full strength
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.9);
+
+    // Then the synapse depresses below the threshold
+    cmp_f32(neuron.run_cycle(ChargeCycle::Odd), 0.0);
+    assert!(neuron.get_synapse_efficacies()[0] < 5. / 6.);
+
+    // And recovers once the input goes away
+    s1.set_measure(0.0);
+    let mut cycle = ChargeCycle::Even;
+    for _ in 0..20 {
+        neuron.run_cycle(cycle);
+        cycle = cycle.next_cycle();
+    }
+
+    s1.set_measure(0.9);
+    cmp_f32(neuron.run_cycle(cycle), 0.9);
+
+    // The learned weight was never touched
+    cmp_f32(neuron.get_weights()[0], 6.);
+}
+
+#[test]
+fn test_synapse_override() {
+    let neuron = Neuron::new(5., 8., 0.);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        6.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+
+    neuron.set_synapse_short_term_plasticity(
+        1,
+        Some(ShortTermPlasticity::Facilitation {
+            facilitation: 0.5,
+            max_efficacy: 2.0,
+            recovery: 0.0,
+        }),
+    );
+
+    s1.set_measure(1.0);
+    s2.set_measure(1.0);
+    neuron.run_static_cycle(ChargeCycle::Even);
+
+    let efficacies = neuron.get_synapse_efficacies();
+    cmp_f32(efficacies[0], 1.0);
+    cmp_f32(efficacies[1], 1.5);
+
+    neuron.clear();
+    cmp_f32(neuron.get_synapse_efficacies()[1], 1.0);
+}
@@ -0,0 +1,362 @@
+//! Layers laid out on 2D grids, and connection builders that use
+//! the layout, for vision-like topologies.  Coordinates are (x, y)
+//! with x along a row, and grids are stored row by row
+
+use crate::kernel::SharedKernel;
+use crate::neuron::{Neuron, Neuronic, NeuronicInput, SynapticType};
+use crate::rng::Rng;
+use crate::weight_init::WeightInit;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+/// Sensors, neurons, or anything else, at (x, y) coordinates
+pub struct Grid<T: ?Sized> {
+    width: usize,
+    height: usize,
+    items: Vec<Rc<T>>,
+}
+
+impl<T: ?Sized> Grid<T> {
+    /// items are in row order, so there must be width * height of them
+    pub fn new(width: usize, height: usize, items: Vec<Rc<T>>) -> Grid<T> {
+        if items.len() != width * height {
+            panic!("Got {} items for a {}x{} grid", items.len(), width, height);
+        }
+
+        Grid {
+            width,
+            height,
+            items,
+        }
+    }
+
+    pub fn from_fn<F: FnMut(usize, usize) -> Rc<T>>(
+        width: usize,
+        height: usize,
+        mut f: F,
+    ) -> Grid<T> {
+        let mut items = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                items.push(f(x, y));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            items,
+        }
+    }
+
+    /// A grid with the same layout, for example to view a grid
+    /// of sensors as a grid of NeuronicInputs
+    pub fn map<U: ?Sized, F: FnMut(&Rc<T>) -> Rc<U>>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            items: self.items.iter().map(f).collect(),
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> &Rc<T> {
+        if x >= self.width || y >= self.height {
+            panic!(
+                "Position ({}, {}) out of bounds for a {}x{} grid",
+                x, y, self.width, self.height
+            );
+        }
+
+        &self.items[(y * self.width) + x]
+    }
+
+    /// Every item, in row order
+    pub fn get_items(&self) -> &[Rc<T>] {
+        &self.items
+    }
+
+    pub fn get_position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+}
+
+/// Scales a connection by the distance between the two ends,
+/// measured in the coordinates of the input grid.  sigma and
+/// radius must be positive
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Falloff {
+    /// 1 at every distance
+    None,
+    /// exp(-d^2 / (2 * sigma^2))
+    Gaussian { sigma: f32 },
+    /// 1 - d / radius, and 0 past radius
+    Linear { radius: f32 },
+}
+
+impl Falloff {
+    pub fn get_factor(&self, distance: f32) -> f32 {
+        match *self {
+            Falloff::None => 1.0,
+            Falloff::Gaussian { sigma } => (-(distance * distance) / (2.0 * sigma * sigma)).exp(),
+            Falloff::Linear { radius } => (1.0 - (distance / radius)).max(0.0),
+        }
+    }
+
+    fn check(&self) {
+        match *self {
+            Falloff::None => (),
+            Falloff::Gaussian { sigma } => {
+                if sigma <= 0. || sigma.is_nan() {
+                    panic!("Falloff sigma {} out of bounds!  Must be positive", sigma);
+                }
+            }
+            Falloff::Linear { radius } => {
+                if radius <= 0. || radius.is_nan() {
+                    panic!("Falloff radius {} out of bounds!  Must be positive", radius);
+                }
+            }
+        }
+    }
+}
+
+/// Connects every neuron on a grid to a width x height patch of
+/// the input grid.  Patches start stride apart, so the neuron at
+/// (x, y) sees the patch whose top left corner is (x * stride, y * stride)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReceptiveField {
+    width: usize,
+    height: usize,
+    stride: usize,
+    init: WeightInit,
+    weight_falloff: Falloff,
+}
+
+impl ReceptiveField {
+    pub fn new(width: usize, height: usize, stride: usize, init: WeightInit) -> ReceptiveField {
+        if width == 0 || height == 0 || stride == 0 {
+            panic!(
+                "Receptive field {}x{} with stride {} must not be empty",
+                width, height, stride
+            );
+        }
+
+        ReceptiveField {
+            width,
+            height,
+            stride,
+            init,
+            weight_falloff: Falloff::None,
+        }
+    }
+
+    /// Scales each starting weight by its distance from
+    /// the center of the patch
+    pub fn with_weight_falloff(mut self, weight_falloff: Falloff) -> ReceptiveField {
+        weight_falloff.check();
+        self.weight_falloff = weight_falloff;
+        self
+    }
+
+    /// Size of the neuron grid that covers an input grid of the given
+    /// size, with patches that don't fit at the edges dropped
+    pub fn get_output_size(&self, input_width: usize, input_height: usize) -> (usize, usize) {
+        if input_width < self.width || input_height < self.height {
+            return (0, 0);
+        }
+
+        (
+            ((input_width - self.width) / self.stride) + 1,
+            ((input_height - self.height) / self.stride) + 1,
+        )
+    }
+
+    /// Creates excitatory synapses, and returns how many were created.
+    /// The neuron grid must be get_output_size of the input grid
+    pub fn connect<N: Neuronic + ?Sized>(
+        &self,
+        rng: &mut Rng,
+        inputs: &Grid<dyn NeuronicInput>,
+        neurons: &Grid<N>,
+    ) -> usize {
+        self.check_output_size(inputs, neurons);
+
+        let fan_in = self.width * self.height;
+        let center_x = (self.width - 1) as f32 / 2.0;
+        let center_y = (self.height - 1) as f32 / 2.0;
+
+        for (index, neuron) in neurons.items.iter().enumerate() {
+            let (x, y) = neurons.get_position(index);
+
+            for dy in 0..self.height {
+                for dx in 0..self.width {
+                    let distance = distance((dx as f32, dy as f32), (center_x, center_y));
+                    let weight =
+                        self.init.sample(rng, fan_in) * self.weight_falloff.get_factor(distance);
+
+                    neuron.create_synapse(
+                        weight,
+                        SynapticType::Excitatory,
+                        Rc::clone(inputs.get((x * self.stride) + dx, (y * self.stride) + dy)),
+                    );
+                }
+            }
+        }
+
+        neurons.items.len() * fan_in
+    }
+
+    /// Like connect, but every neuron's patch shares the weights of
+    /// the kernel, which has a weight for every position in the patch,
+    /// in row order.  The field's init and weight falloff aren't used
+    pub fn connect_shared(
+        &self,
+        kernel: &Rc<SharedKernel>,
+        inputs: &Grid<dyn NeuronicInput>,
+        neurons: &Grid<Neuron>,
+    ) -> usize {
+        let fan_in = self.width * self.height;
+
+        if kernel.len() != fan_in {
+            panic!(
+                "A kernel of size {} doesn't match a {}x{} receptive field",
+                kernel.len(),
+                self.width,
+                self.height
+            );
+        }
+        self.check_output_size(inputs, neurons);
+
+        for (index, neuron) in neurons.items.iter().enumerate() {
+            let (x, y) = neurons.get_position(index);
+
+            for dy in 0..self.height {
+                for dx in 0..self.width {
+                    neuron.create_shared_synapse(
+                        kernel,
+                        (dy * self.width) + dx,
+                        Rc::clone(inputs.get((x * self.stride) + dx, (y * self.stride) + dy)),
+                    );
+                }
+            }
+        }
+
+        neurons.items.len() * fan_in
+    }
+
+    fn check_output_size<N: ?Sized>(&self, inputs: &Grid<dyn NeuronicInput>, neurons: &Grid<N>) {
+        let output_size = self.get_output_size(inputs.width, inputs.height);
+
+        if (neurons.width, neurons.height) != output_size {
+            panic!(
+                "A {}x{} neuron grid doesn't match the {}x{} output of the receptive field",
+                neurons.width, neurons.height, output_size.0, output_size.1
+            );
+        }
+    }
+}
+
+/// Connects neurons to inputs depending on how far apart they are,
+/// after the neuron grid is stretched over the input grid, so a
+/// neuron's position is measured in input coordinates whatever the
+/// sizes of the two grids.  Each pair is connected with probability
+/// given by one falloff, and its starting weight is scaled by another
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistanceConnector {
+    init: WeightInit,
+    probability: Falloff,
+    weight_falloff: Falloff,
+}
+
+impl DistanceConnector {
+    /// Connects every pair, with unscaled weights
+    pub fn new(init: WeightInit) -> DistanceConnector {
+        DistanceConnector {
+            init,
+            probability: Falloff::None,
+            weight_falloff: Falloff::None,
+        }
+    }
+
+    pub fn with_probability(mut self, probability: Falloff) -> DistanceConnector {
+        probability.check();
+        self.probability = probability;
+        self
+    }
+
+    pub fn with_weight_falloff(mut self, weight_falloff: Falloff) -> DistanceConnector {
+        weight_falloff.check();
+        self.weight_falloff = weight_falloff;
+        self
+    }
+
+    /// Creates excitatory synapses, and returns how many were created
+    pub fn connect<N: Neuronic + ?Sized>(
+        &self,
+        rng: &mut Rng,
+        inputs: &Grid<dyn NeuronicInput>,
+        neurons: &Grid<N>,
+    ) -> usize {
+        let mut count = 0;
+
+        for (index, neuron) in neurons.items.iter().enumerate() {
+            let (x, y) = neurons.get_position(index);
+            let position = (
+                project(x, neurons.width, inputs.width),
+                project(y, neurons.height, inputs.height),
+            );
+
+            let chosen: Vec<(usize, f32)> = (0..inputs.items.len())
+                .filter_map(|input_index| {
+                    let (input_x, input_y) = inputs.get_position(input_index);
+                    let distance = distance(position, (input_x as f32, input_y as f32));
+
+                    if rng.chance(self.probability.get_factor(distance)) {
+                        Some((input_index, distance))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            let fan_in = chosen.len();
+
+            for (input_index, distance) in chosen {
+                let weight =
+                    self.init.sample(rng, fan_in) * self.weight_falloff.get_factor(distance);
+
+                neuron.create_synapse(
+                    weight,
+                    SynapticType::Excitatory,
+                    Rc::clone(&inputs.items[input_index]),
+                );
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+/// Position of the center of cell i of a row with from cells,
+/// in a row with to cells covering the same span
+fn project(i: usize, from: usize, to: usize) -> f32 {
+    (((i as f32 + 0.5) * to as f32) / from as f32) - 0.5
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod spatial_tests;
@@ -0,0 +1,290 @@
+use crate::kernel::SharedKernel;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::rng::Rng;
+use crate::spatial::{DistanceConnector, Falloff, Grid, ReceptiveField};
+use crate::test_utils::cmp_f32;
+use crate::weight_bounds::{ClipPolicy, WeightBounds};
+use crate::weight_init::WeightInit;
+use std::rc::Rc;
+
+fn sensor_grid(width: usize, height: usize) -> Grid<NeuronicSensor> {
+    Grid::from_fn(width, height, |_, _| Rc::new(NeuronicSensor::new()))
+}
+
+fn neuron_grid(width: usize, height: usize) -> Grid<Neuron> {
+    Grid::from_fn(width, height, |_, _| Rc::new(Neuron::new(1., 8., 0.1)))
+}
+
+fn as_inputs(sensors: &Grid<NeuronicSensor>) -> Grid<dyn NeuronicInput> {
+    sensors.map(|sensor| Rc::clone(sensor) as Rc<dyn NeuronicInput>)
+}
+
+#[test]
+fn test_grid() {
+    let grid = Grid::from_fn(3, 2, |x, y| Rc::new((x, y)));
+
+    assert_eq!(grid.get_width(), 3);
+    assert_eq!(grid.get_height(), 2);
+    assert_eq!(*grid.get(2, 1).as_ref(), (2, 1));
+    assert_eq!(*grid.get_items()[4], (1, 1));
+    assert_eq!(grid.get_position(4), (1, 1));
+
+    let mapped = grid.map(|item| Rc::new(item.0 + item.1));
+    assert_eq!(*mapped.get(2, 1).as_ref(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_grid_size_mismatch() {
+    Grid::new(2, 2, (0..3).map(Rc::new).collect());
+}
+
+#[test]
+#[should_panic]
+fn test_grid_out_of_bounds() {
+    sensor_grid(2, 2).get(2, 0);
+}
+
+#[test]
+fn test_falloff() {
+    cmp_f32(Falloff::None.get_factor(10.), 1.);
+    cmp_f32(Falloff::Gaussian { sigma: 1. }.get_factor(0.), 1.);
+    cmp_f32(
+        Falloff::Gaussian { sigma: 2. }.get_factor(2.),
+        (-0.5f32).exp(),
+    );
+    cmp_f32(Falloff::Linear { radius: 4. }.get_factor(1.), 0.75);
+    cmp_f32(Falloff::Linear { radius: 4. }.get_factor(5.), 0.);
+}
+
+#[test]
+fn test_output_size() {
+    let field = ReceptiveField::new(3, 3, 1, WeightInit::Constant(1.));
+    assert_eq!(field.get_output_size(5, 4), (3, 2));
+
+    let field = ReceptiveField::new(2, 2, 2, WeightInit::Constant(1.));
+    assert_eq!(field.get_output_size(5, 4), (2, 2));
+    assert_eq!(field.get_output_size(1, 4), (0, 0));
+}
+
+#[test]
+fn test_receptive_field() {
+    let sensors = sensor_grid(4, 4);
+    let neurons = neuron_grid(2, 2);
+
+    let field = ReceptiveField::new(2, 2, 2, WeightInit::Constant(2.));
+    let count = field.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons);
+
+    assert_eq!(count, 16);
+    for neuron in neurons.get_items().iter() {
+        assert_eq!(neuron.get_weights(), vec![2.; 4]);
+    }
+
+    // Only the neuron whose patch covers the sensor responds to it
+    sensors.get(3, 2).set_measure(0.7);
+
+    for (index, neuron) in neurons.get_items().iter().enumerate() {
+        let expected = if neurons.get_position(index) == (1, 1) {
+            0.7
+        } else {
+            0.
+        };
+        cmp_f32(neuron.run_static_cycle(ChargeCycle::Even), expected);
+    }
+}
+
+#[test]
+fn test_overlapping_fields() {
+    let sensors = sensor_grid(4, 1);
+    let neurons = neuron_grid(2, 1);
+
+    // Stride 1 and width 3, so both neurons see the middle sensors
+    let field = ReceptiveField::new(3, 1, 1, WeightInit::Constant(2.));
+    field.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons);
+
+    sensors.get(1, 0).set_measure(0.4);
+
+    cmp_f32(neurons.get(0, 0).run_static_cycle(ChargeCycle::Even), 0.4);
+    cmp_f32(neurons.get(1, 0).run_static_cycle(ChargeCycle::Even), 0.4);
+
+    sensors.get(1, 0).set_measure(0.);
+    sensors.get(3, 0).set_measure(0.4);
+
+    cmp_f32(neurons.get(0, 0).run_static_cycle(ChargeCycle::Odd), 0.);
+    cmp_f32(neurons.get(1, 0).run_static_cycle(ChargeCycle::Odd), 0.4);
+}
+
+#[test]
+fn test_receptive_field_falloff() {
+    let sensors = sensor_grid(3, 3);
+    let neurons = neuron_grid(1, 1);
+
+    let field = ReceptiveField::new(3, 3, 1, WeightInit::Constant(2.))
+        .with_weight_falloff(Falloff::Linear { radius: 2. });
+    field.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons);
+
+    let weights = neurons.get(0, 0).get_weights();
+
+    // Center, edge and corner of the patch
+    cmp_f32(weights[4], 2.);
+    cmp_f32(weights[1], 1.);
+    cmp_f32(weights[0], 2. * (1. - (2f32.sqrt() / 2.)));
+}
+
+#[test]
+#[should_panic]
+fn test_receptive_field_wrong_output() {
+    let field = ReceptiveField::new(2, 2, 1, WeightInit::Constant(1.));
+    field.connect(
+        &mut Rng::new(0),
+        &as_inputs(&sensor_grid(4, 4)),
+        &neuron_grid(2, 2),
+    );
+}
+
+#[test]
+fn test_distance_connector() {
+    let sensors = sensor_grid(8, 8);
+
+    // Every pair is connected without a probability falloff
+    let neurons = neuron_grid(2, 2);
+    let connector = DistanceConnector::new(WeightInit::Constant(1.));
+    assert_eq!(
+        connector.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons),
+        256
+    );
+
+    // Only a neuron sitting right on a sensor is sure to connect to it
+    // with a linear falloff, so grids of the same size connect one to one
+    let neurons = neuron_grid(8, 8);
+    let connector = DistanceConnector::new(WeightInit::Constant(1.))
+        .with_probability(Falloff::Linear { radius: 1. });
+    assert_eq!(
+        connector.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons),
+        64
+    );
+
+    sensors.get(6, 1).set_measure(0.5);
+
+    cmp_f32(neurons.get(6, 1).run_static_cycle(ChargeCycle::Even), 0.5);
+    cmp_f32(neurons.get(5, 1).run_static_cycle(ChargeCycle::Even), 0.);
+}
+
+#[test]
+fn test_stretched_grid() {
+    // A 1x1 grid stretched over a 3x3 one sits at its center
+    let sensors = sensor_grid(3, 3);
+    let neurons = neuron_grid(1, 1);
+
+    let connector = DistanceConnector::new(WeightInit::Constant(1.))
+        .with_probability(Falloff::Linear { radius: 1. });
+    assert_eq!(
+        connector.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons),
+        1
+    );
+
+    sensors.get(1, 1).set_measure(0.5);
+    cmp_f32(neurons.get(0, 0).run_static_cycle(ChargeCycle::Even), 0.5);
+}
+
+#[test]
+fn test_distance_weights() {
+    let sensors = sensor_grid(5, 1);
+    let neurons = neuron_grid(1, 1);
+
+    let connector = DistanceConnector::new(WeightInit::Constant(4.))
+        .with_weight_falloff(Falloff::Gaussian { sigma: 1. });
+    connector.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons);
+
+    let weights = neurons.get(0, 0).get_weights();
+    assert_eq!(weights.len(), 5);
+
+    cmp_f32(weights[2], 4.);
+    cmp_f32(weights[1], weights[3]);
+    cmp_f32(weights[0], 4. * (-2f32).exp());
+}
+
+#[test]
+fn test_dyn_neuron_grid() {
+    let sensors = sensor_grid(3, 3);
+    let neurons: Grid<dyn Neuronic> = Grid::from_fn(1, 1, |_, _| {
+        Rc::new(Neuron::new(1., 8., 0.1)) as Rc<dyn Neuronic>
+    });
+
+    let field = ReceptiveField::new(3, 3, 1, WeightInit::Constant(1.));
+    assert_eq!(
+        field.connect(&mut Rng::new(0), &as_inputs(&sensors), &neurons),
+        9
+    );
+}
+
+#[test]
+fn test_shared_receptive_field() {
+    let sensors = sensor_grid(4, 1);
+    let neurons = neuron_grid(2, 1);
+
+    // Detects a bright pixel to the left of a dim one
+    let kernel = Rc::new(SharedKernel::new(
+        vec![6., 1.],
+        SynapticType::Excitatory,
+        WeightBounds::new(0., 8., ClipPolicy::Hard),
+    ));
+
+    let field = ReceptiveField::new(2, 1, 2, WeightInit::Constant(0.));
+    assert_eq!(
+        field.connect_shared(&kernel, &as_inputs(&sensors), &neurons),
+        4
+    );
+
+    // The same feature is detected wherever it is
+    sensors.get(0, 0).set_measure(0.9);
+    sensors.get(1, 0).set_measure(0.2);
+    sensors.get(2, 0).set_measure(0.9);
+    sensors.get(3, 0).set_measure(0.2);
+
+    cmp_f32(neurons.get(0, 0).run_static_cycle(ChargeCycle::Even), 0.9);
+    cmp_f32(neurons.get(1, 0).run_static_cycle(ChargeCycle::Even), 0.9);
+
+    // Weakening the kernel weakens every neuron, which now
+    // need both pixels to reach their threshold
+    kernel.set_weights(&[0.5, 0.5]);
+    cmp_f32(neurons.get(0, 0).run_static_cycle(ChargeCycle::Odd), 0.2);
+    cmp_f32(neurons.get(1, 0).run_static_cycle(ChargeCycle::Odd), 0.2);
+}
+
+#[test]
+#[should_panic]
+fn test_shared_kernel_size_mismatch() {
+    let kernel = Rc::new(SharedKernel::new(
+        vec![1.; 3],
+        SynapticType::Excitatory,
+        WeightBounds::new(0., 8., ClipPolicy::Hard),
+    ));
+
+    ReceptiveField::new(2, 1, 1, WeightInit::Constant(0.)).connect_shared(
+        &kernel,
+        &as_inputs(&sensor_grid(3, 1)),
+        &neuron_grid(2, 1),
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_zero_sigma() {
+    ReceptiveField::new(3, 3, 1, WeightInit::Constant(1.))
+        .with_weight_falloff(Falloff::Gaussian { sigma: 0. });
+}
+
+#[test]
+#[should_panic]
+fn test_zero_radius() {
+    DistanceConnector::new(WeightInit::Constant(1.))
+        .with_probability(Falloff::Linear { radius: 0. });
+}
+
+#[test]
+#[should_panic]
+fn test_negative_weight_falloff_radius() {
+    DistanceConnector::new(WeightInit::Constant(1.))
+        .with_weight_falloff(Falloff::Linear { radius: -1. });
+}
@@ -0,0 +1,100 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, TeacherMode};
+use std::rc::Rc;
+
+/// Drives teacher forcing on a group of readout neurons, one target
+/// per readout.  For readouts that are part of a Network, call
+/// set_targets before Network::run_cycle and release afterwards.
+/// For standalone readouts, train does all three steps at once
+pub struct Supervisor {
+    readouts: Vec<Rc<Neuron>>,
+    mode: TeacherMode,
+}
+
+impl Supervisor {
+    pub fn new(readouts: Vec<Rc<Neuron>>, mode: TeacherMode) -> Supervisor {
+        Supervisor { readouts, mode }
+    }
+
+    pub fn get_readouts(&self) -> &[Rc<Neuron>] {
+        &self.readouts
+    }
+
+    pub fn set_targets(&self, targets: &[f32]) {
+        if targets.len() != self.readouts.len() {
+            panic!(
+                "Got {} targets for {} readouts",
+                targets.len(),
+                self.readouts.len()
+            );
+        }
+
+        for (readout, target) in self.readouts.iter().zip(targets.iter()) {
+            readout.set_teacher(*target, self.mode);
+        }
+    }
+
+    /// Clears the teacher from every readout
+    pub fn release(&self) {
+        for readout in self.readouts.iter() {
+            readout.clear_teacher();
+        }
+    }
+
+    /// Runs a learning cycle on every readout with the targets
+    /// clamped, and returns the measures the readouts emitted
+    pub fn train(&self, cycle: ChargeCycle, targets: &[f32]) -> Vec<f32> {
+        self.set_targets(targets);
+
+        let measures = self
+            .readouts
+            .iter()
+            .map(|readout| readout.run_cycle(cycle))
+            .collect();
+
+        self.release();
+        measures
+    }
+
+    /// Runs a static cycle on every readout with nothing clamped,
+    /// and returns the measures
+    pub fn predict(&self, cycle: ChargeCycle) -> Vec<f32> {
+        self.readouts
+            .iter()
+            .map(|readout| readout.run_static_cycle(cycle))
+            .collect()
+    }
+
+    /// Index of the readout with the largest measure on a cycle,
+    /// or None if no readout fired
+    pub fn classify(&self, cycle: ChargeCycle) -> Option<usize> {
+        let mut best: Option<(usize, f32)> = None;
+
+        for (index, readout) in self.readouts.iter().enumerate() {
+            let measure = readout.get_measure(cycle);
+
+            if measure > best.map_or(0.0, |(_, best_measure)| best_measure) {
+                best = Some((index, measure));
+            }
+        }
+
+        best.map(|(index, _)| index)
+    }
+}
+
+/// Targets for a classification label, 1 for the
+/// label's readout and 0 for every other readout
+pub fn one_hot(label: usize, readout_count: usize) -> Vec<f32> {
+    if label >= readout_count {
+        panic!(
+            "Label {} out of range for {} readouts",
+            label, readout_count
+        );
+    }
+
+    let mut targets = vec![0.0; readout_count];
+    targets[label] = 1.0;
+    targets
+}
+
+#[cfg(test)]
+mod supervised_tests;
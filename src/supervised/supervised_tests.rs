@@ -0,0 +1,124 @@
+use crate::neuron::{
+    ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType, TeacherMode,
+};
+use crate::supervised::{one_hot, Supervisor};
+use crate::test_utils::cmp_f32;
+use std::rc::Rc;
+
+#[test]
+fn test_learning_teacher() {
+    let s1 = Rc::new(NeuronicSensor::new());
+    s1.set_measure(0.5);
+
+    // The threshold is too high for the neuron to fire on its own
+    let neuron = Neuron::new(10., 8., 0.1);
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    neuron.set_teacher(0.8, TeacherMode::Learning);
+    assert_eq!(neuron.get_teacher(), Some((0.8, TeacherMode::Learning)));
+
+    // The neuron still emits its natural measure...
+    cmp_f32(neuron.run_cycle(ChargeCycle::Even), 0.0);
+    cmp_f32(neuron.get_measure(ChargeCycle::Even), 0.0);
+
+    // ...but learns as if it had fired the target
+    cmp_f32(neuron.get_weights()[0], 4. + (0.1 * (8. - 4.) * 0.5));
+
+    neuron.clear_teacher();
+    assert_eq!(neuron.get_teacher(), None);
+}
+
+#[test]
+fn test_propagating_teacher() {
+    let s1 = Rc::new(NeuronicSensor::new());
+    s1.set_measure(0.5);
+
+    let n1 = Rc::new(Neuron::new(10., 8., 0.1));
+    n1.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+
+    let n2 = Neuron::new(1., 8., 0.1);
+    n2.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&n1) as Rc<dyn NeuronicInput>,
+    );
+
+    n1.set_teacher(0.8, TeacherMode::Propagate);
+
+    cmp_f32(n1.run_cycle(ChargeCycle::Even), 0.8);
+    cmp_f32(n1.get_measure(ChargeCycle::Even), 0.8);
+
+    // The downstream neuron sees the target
+    cmp_f32(n2.run_static_cycle(ChargeCycle::Odd), 0.8);
+}
+
+#[test]
+#[should_panic]
+fn test_teacher_out_of_bounds() {
+    Neuron::new(10., 8., 0.1).set_teacher(1.5, TeacherMode::Learning);
+}
+
+#[test]
+fn test_one_hot() {
+    assert_eq!(one_hot(2, 4), vec![0., 0., 1., 0.]);
+}
+
+#[test]
+fn test_supervisor_classification() {
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+
+    let readouts: Vec<Rc<Neuron>> = (0..2)
+        .map(|_| {
+            let readout = Rc::new(Neuron::new(4., 8., 0.2));
+            readout.create_synapse(
+                2.,
+                SynapticType::Excitatory,
+                Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+            );
+            readout.create_synapse(
+                2.,
+                SynapticType::Excitatory,
+                Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+            );
+            readout
+        })
+        .collect();
+
+    let supervisor = Supervisor::new(readouts, TeacherMode::Learning);
+
+    let patterns = [(0.9, 0.1), (0.1, 0.9)];
+
+    let mut cycle = ChargeCycle::Even;
+    for _ in 0..10 {
+        for (label, (s1_measure, s2_measure)) in patterns.iter().enumerate() {
+            s1.set_measure(*s1_measure);
+            s2.set_measure(*s2_measure);
+
+            supervisor.train(cycle, &one_hot(label, 2));
+            cycle = cycle.next_cycle();
+        }
+    }
+
+    // Teachers are released after training
+    for readout in supervisor.get_readouts() {
+        assert_eq!(readout.get_teacher(), None);
+    }
+
+    for (label, (s1_measure, s2_measure)) in patterns.iter().enumerate() {
+        s1.set_measure(*s1_measure);
+        s2.set_measure(*s2_measure);
+
+        supervisor.predict(cycle);
+        assert_eq!(supervisor.classify(cycle), Some(label));
+        cycle = cycle.next_cycle();
+    }
+}
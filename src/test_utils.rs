@@ -0,0 +1,13 @@
+//! Helpers shared by the unit tests of every module
+
+/// Utility method that compares f32 to
+/// three decimal places
+pub fn cmp_f32(f1: f32, f2: f32) {
+    assert_eq!(
+        (f1 * 1000.).floor(),
+        (f2 * 1000.).floor(),
+        "{} does not equal {}",
+        f1,
+        f2
+    );
+}
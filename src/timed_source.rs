@@ -0,0 +1,142 @@
+use crate::clock::Clock;
+use crate::neuron::{ChargeCycle, NeuronicInput};
+use crate::rng::Rng;
+use std::rc::Rc;
+
+enum Signal {
+    Sine { period: f32, phase: f32 },
+    Square { period: u64, duty: f32 },
+    Replay { values: Vec<f32>, looping: bool },
+    Poisson { rate: f32, seed: u64 },
+    Function(Box<dyn Fn(u64) -> f32>),
+}
+
+/// An input whose measure is a function of the tick of a clock,
+/// usually a Network's, so inputs don't need to be set by hand every
+/// cycle.  The measure only depends on the tick, so every neuron reading
+/// the source during a cycle sees the same measure
+pub struct TimedSource {
+    clock: Rc<Clock>,
+    signal: Signal,
+}
+
+impl TimedSource {
+    /// 0.5 + 0.5 * sin(2 * pi * t / period + phase)
+    pub fn sine(clock: Rc<Clock>, period: f32, phase: f32) -> TimedSource {
+        if period <= 0. {
+            panic!("Period {} out of bounds!  Must be positive", period);
+        }
+
+        TimedSource {
+            clock,
+            signal: Signal::Sine { period, phase },
+        }
+    }
+
+    /// 1 for the first duty fraction of every period ticks, and 0 for the rest
+    pub fn square(clock: Rc<Clock>, period: u64, duty: f32) -> TimedSource {
+        if period == 0 {
+            panic!("Period must be positive");
+        }
+
+        if !(0. ..=1.).contains(&duty) {
+            panic!("Duty {} out of bounds!  Must be in [0.0, 1.0]", duty);
+        }
+
+        TimedSource {
+            clock,
+            signal: Signal::Square { period, duty },
+        }
+    }
+
+    /// values[t], a recorded sequence.  Once the sequence runs out it
+    /// starts over if looping, and is 0 otherwise
+    pub fn replay(clock: Rc<Clock>, values: Vec<f32>, looping: bool) -> TimedSource {
+        if let Some(value) = values.iter().find(|value| !(0. ..=1.).contains(*value)) {
+            panic!(
+                "Measure {} out of bounds!  Measure must be between 0.0 and 1.0",
+                value
+            );
+        }
+
+        TimedSource {
+            clock,
+            signal: Signal::Replay { values, looping },
+        }
+    }
+
+    /// Spikes to 1 with probability rate on each tick, and is 0 otherwise.
+    /// The same seed gives the same spike train
+    pub fn poisson(clock: Rc<Clock>, rate: f32, seed: u64) -> TimedSource {
+        if !(0. ..=1.).contains(&rate) {
+            panic!("Rate {} out of bounds!  Must be in [0.0, 1.0]", rate);
+        }
+
+        TimedSource {
+            clock,
+            signal: Signal::Poisson { rate, seed },
+        }
+    }
+
+    /// f(t), clamped to [0, 1], with NaN as 0
+    pub fn from_fn<F: Fn(u64) -> f32 + 'static>(clock: Rc<Clock>, f: F) -> TimedSource {
+        TimedSource {
+            clock,
+            signal: Signal::Function(Box::new(f)),
+        }
+    }
+
+    /// The measure of the source at a tick
+    pub fn get_value(&self, tick: u64) -> f32 {
+        let value = match &self.signal {
+            Signal::Sine { period, phase } => {
+                let angle = (2.0 * std::f32::consts::PI * tick as f32 / period) + phase;
+                0.5 + (0.5 * angle.sin())
+            }
+            Signal::Square { period, duty } => {
+                if ((tick % period) as f32) < (duty * *period as f32) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Signal::Replay { values, looping } => {
+                if values.is_empty() {
+                    0.0
+                } else if *looping {
+                    values[(tick % values.len() as u64) as usize]
+                } else {
+                    values.get(tick as usize).copied().unwrap_or(0.0)
+                }
+            }
+            Signal::Poisson { rate, seed } => {
+                // Seeding from the tick, rather than keeping an Rng,
+                // lets the spike at any tick be looked up again
+                let mut rng = Rng::new(seed ^ tick.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+                if rng.chance(*rate) {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Signal::Function(f) => f(tick),
+        };
+
+        // clamp lets NaN through, which isn't a measure
+        if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        }
+    }
+}
+
+impl NeuronicInput for TimedSource {
+    fn get_measure(&self, _cycle: ChargeCycle) -> f32 {
+        self.get_value(self.clock.get_tick())
+    }
+}
+
+#[cfg(test)]
+mod timed_source_tests;
@@ -0,0 +1,117 @@
+use crate::clock::Clock;
+use crate::network::Network;
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, SynapticType};
+use crate::test_utils::cmp_f32;
+use crate::timed_source::TimedSource;
+use std::rc::Rc;
+
+#[test]
+fn test_sine() {
+    let clock = Rc::new(Clock::new());
+    let source = TimedSource::sine(Rc::clone(&clock), 4., 0.);
+
+    cmp_f32(source.get_measure(ChargeCycle::Even), 0.5);
+
+    clock.advance();
+    cmp_f32(source.get_measure(ChargeCycle::Odd), 1.);
+
+    cmp_f32(source.get_value(3), 0.);
+    cmp_f32(source.get_value(4), source.get_value(0));
+}
+
+#[test]
+fn test_square() {
+    let source = TimedSource::square(Rc::new(Clock::new()), 4, 0.5);
+
+    let values: Vec<f32> = (0..8).map(|tick| source.get_value(tick)).collect();
+    assert_eq!(values, vec![1., 1., 0., 0., 1., 1., 0., 0.]);
+}
+
+#[test]
+fn test_replay() {
+    let clock = Rc::new(Clock::new());
+
+    let once = TimedSource::replay(Rc::clone(&clock), vec![0.2, 0.4, 0.6], false);
+    let looping = TimedSource::replay(Rc::clone(&clock), vec![0.2, 0.4, 0.6], true);
+
+    assert_eq!(
+        (0..5).map(|tick| once.get_value(tick)).collect::<Vec<_>>(),
+        vec![0.2, 0.4, 0.6, 0., 0.]
+    );
+    assert_eq!(
+        (0..5)
+            .map(|tick| looping.get_value(tick))
+            .collect::<Vec<_>>(),
+        vec![0.2, 0.4, 0.6, 0.2, 0.4]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_replay_out_of_bounds() {
+    TimedSource::replay(Rc::new(Clock::new()), vec![0.2, 1.2], false);
+}
+
+#[test]
+fn test_poisson() {
+    let clock = Rc::new(Clock::new());
+    let source = TimedSource::poisson(Rc::clone(&clock), 0.2, 5);
+
+    let spikes: Vec<f32> = (0..1000).map(|tick| source.get_value(tick)).collect();
+    assert!(spikes.iter().all(|spike| *spike == 0. || *spike == 1.));
+
+    let count = spikes.iter().filter(|spike| **spike == 1.).count();
+    assert!(count > 150 && count < 250, "{} spikes", count);
+
+    // Looking a tick up again gives the same spike, and the same
+    // seed gives the same train
+    let again = TimedSource::poisson(clock, 0.2, 5);
+    assert!((0..1000).all(|tick| again.get_value(tick) == spikes[tick as usize]));
+
+    let other = TimedSource::poisson(Rc::new(Clock::new()), 0.2, 6);
+    assert!((0..1000).any(|tick| other.get_value(tick) != spikes[tick as usize]));
+}
+
+#[test]
+fn test_from_fn() {
+    let source = TimedSource::from_fn(Rc::new(Clock::new()), |tick| tick as f32 / 4.);
+
+    cmp_f32(source.get_value(1), 0.25);
+
+    // Clamped to a valid measure
+    cmp_f32(source.get_value(10), 1.);
+
+    let undefined = TimedSource::from_fn(Rc::new(Clock::new()), |tick| match tick {
+        0 => f32::NAN,
+        1 => f32::INFINITY,
+        _ => f32::NEG_INFINITY,
+    });
+
+    assert_eq!(undefined.get_value(0), 0.);
+    assert_eq!(undefined.get_value(1), 1.);
+    assert_eq!(undefined.get_value(2), 0.);
+}
+
+#[test]
+fn test_driven_by_network() {
+    let mut network = Network::new();
+
+    let source = Rc::new(TimedSource::square(network.get_clock(), 2, 0.5));
+    let neuron = Rc::new(Neuron::new(1., 8., 0.1));
+    neuron.create_synapse(
+        2.,
+        SynapticType::Excitatory,
+        Rc::clone(&source) as Rc<dyn NeuronicInput>,
+    );
+    network.add_neuron(Rc::clone(&neuron) as Rc<dyn Neuronic>);
+
+    // The source alternates on its own as the network runs
+    network.run_static_cycle();
+    cmp_f32(neuron.get_measure(ChargeCycle::Even), 1.);
+
+    network.run_static_cycle();
+    cmp_f32(neuron.get_measure(ChargeCycle::Odd), 0.);
+
+    network.run_static_cycle();
+    cmp_f32(neuron.get_measure(ChargeCycle::Even), 1.);
+}
@@ -0,0 +1,123 @@
+use crate::neuron::SynapticType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// What happens to a synaptic weight that an update would push past its bounds
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClipPolicy {
+    /// The weight is set to the bound it crossed.  Note that a weight
+    /// sitting exactly at max_synapse_weight stops learning, since the
+    /// learning rule is a spring towards max_synapse_weight
+    Hard,
+    /// Updates are scaled by how far the weight is from the bound it's
+    /// moving towards, so weights slow down as they approach a bound
+    Soft,
+    /// The weight bounces off the bound it crossed
+    /// by the same distance it overshot
+    Reflect,
+}
+
+/// Minimum and maximum synaptic weights, separately for excitatory and
+/// inhibitory synapses, and the policy for keeping weights between them.
+/// Inhibitory weights are stored as positive numbers, and are only
+/// negated when they become impulses
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightBounds {
+    excitatory: (f32, f32),
+    inhibitory: (f32, f32),
+    policy: ClipPolicy,
+}
+
+impl WeightBounds {
+    /// Uses the same bounds for both synaptic types
+    pub fn new(min: f32, max: f32, policy: ClipPolicy) -> WeightBounds {
+        check_bounds(min, max);
+
+        WeightBounds {
+            excitatory: (min, max),
+            inhibitory: (min, max),
+            policy,
+        }
+    }
+
+    pub fn with_excitatory(mut self, min: f32, max: f32) -> WeightBounds {
+        check_bounds(min, max);
+        self.excitatory = (min, max);
+        self
+    }
+
+    pub fn with_inhibitory(mut self, min: f32, max: f32) -> WeightBounds {
+        check_bounds(min, max);
+        self.inhibitory = (min, max);
+        self
+    }
+
+    /// Returns (min, max) for a synaptic type.  Shunting synapses
+    /// use the inhibitory bounds and every other type the excitatory
+    /// bounds, although only excitatory and inhibitory synapses learn
+    pub fn get_bounds(&self, synaptic_type: SynapticType) -> (f32, f32) {
+        match synaptic_type {
+            SynapticType::Inhibitory | SynapticType::Shunting => self.inhibitory,
+            _ => self.excitatory,
+        }
+    }
+
+    pub fn get_policy(&self) -> ClipPolicy {
+        self.policy
+    }
+
+    /// Applies a weight update, and returns the new weight
+    pub fn apply(&self, synaptic_type: SynapticType, weight: f32, delta: f32) -> f32 {
+        let (min, max) = self.get_bounds(synaptic_type);
+
+        let new_weight = match self.policy {
+            ClipPolicy::Hard => weight + delta,
+            ClipPolicy::Soft => {
+                let range = max - min;
+
+                if range <= 0.0 {
+                    min
+                } else if delta > 0.0 {
+                    weight + (delta * (max - weight) / range)
+                } else {
+                    weight + (delta * (weight - min) / range)
+                }
+            }
+            ClipPolicy::Reflect => {
+                let new_weight = weight + delta;
+
+                if new_weight > max {
+                    max - (new_weight - max)
+                } else if new_weight < min {
+                    min + (min - new_weight)
+                } else {
+                    new_weight
+                }
+            }
+        };
+
+        // Soft and Reflect can still end up out of bounds for large
+        // enough updates, so every policy finishes with a hard clip
+        new_weight.clamp(min, max)
+    }
+}
+
+fn check_bounds(min: f32, max: f32) {
+    for bound in [min, max].iter() {
+        if !bound.is_finite() {
+            panic!("Weight bound {} out of bounds!  Must be finite", bound);
+        }
+    }
+
+    if min > max {
+        panic!(
+            "Minimum weight {} is larger than maximum weight {}",
+            min, max
+        );
+    }
+}
+
+#[cfg(test)]
+mod weight_bounds_tests;
@@ -0,0 +1,145 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::test_utils::cmp_f32;
+use crate::weight_bounds::{ClipPolicy, WeightBounds};
+use std::rc::Rc;
+
+#[test]
+fn test_separate_bounds() {
+    let bounds = WeightBounds::new(0., 8., ClipPolicy::Hard)
+        .with_excitatory(1., 6.)
+        .with_inhibitory(0.5, 3.);
+
+    assert_eq!(bounds.get_bounds(SynapticType::Excitatory), (1., 6.));
+    assert_eq!(bounds.get_bounds(SynapticType::Inhibitory), (0.5, 3.));
+
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 5., 4.), 6.);
+    cmp_f32(bounds.apply(SynapticType::Inhibitory, 2., 4.), 3.);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 2., -4.), 1.);
+    cmp_f32(bounds.apply(SynapticType::Inhibitory, 2., -4.), 0.5);
+}
+
+#[test]
+fn test_hard_clip() {
+    let bounds = WeightBounds::new(0., 8., ClipPolicy::Hard);
+
+    // Updates inside the bounds are untouched
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 4., 1.5), 5.5);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 4., -1.5), 2.5);
+
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 7., 2.), 8.);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 1., -2.), 0.);
+}
+
+#[test]
+fn test_soft_bound() {
+    let bounds = WeightBounds::new(0., 8., ClipPolicy::Soft);
+
+    // Increases shrink near the max, decreases shrink near the min
+    cmp_f32(
+        bounds.apply(SynapticType::Excitatory, 6., 2.),
+        6. + (2. * 2. / 8.),
+    );
+    cmp_f32(
+        bounds.apply(SynapticType::Excitatory, 6., -2.),
+        6. - (2. * 6. / 8.),
+    );
+    cmp_f32(
+        bounds.apply(SynapticType::Excitatory, 2., 2.),
+        2. + (2. * 6. / 8.),
+    );
+    cmp_f32(
+        bounds.apply(SynapticType::Excitatory, 2., -2.),
+        2. - (2. * 2. / 8.),
+    );
+
+    // Huge updates still can't leave the bounds
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 6., 100.), 8.);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 6., -100.), 0.);
+}
+
+#[test]
+fn test_reflect() {
+    let bounds = WeightBounds::new(0., 8., ClipPolicy::Reflect);
+
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 4., 1.5), 5.5);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 7., 2.), 7.);
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 1., -1.5), 0.5);
+
+    // Overshooting by more than the whole range clips
+    cmp_f32(bounds.apply(SynapticType::Excitatory, 7., 20.), 0.);
+}
+
+#[test]
+#[should_panic]
+fn test_inverted_bounds() {
+    WeightBounds::new(8., 0., ClipPolicy::Hard);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_bound() {
+    WeightBounds::new(0., f32::NAN, ClipPolicy::Hard);
+}
+
+#[test]
+#[should_panic]
+fn test_infinite_inhibitory_bound() {
+    WeightBounds::new(0., 8., ClipPolicy::Hard).with_inhibitory(f32::NEG_INFINITY, 8.);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_max_synapse_weight() {
+    Neuron::new(1., f32::NAN, 0.1);
+}
+
+#[test]
+fn test_neuron_weight_bounds() {
+    let learning_constant = 3.;
+    let max_weight = 8.;
+
+    let bounds = WeightBounds::new(0., max_weight, ClipPolicy::Hard).with_inhibitory(0., 2.);
+    let neuron = Neuron::new(1., max_weight, learning_constant).with_weight_bounds(bounds);
+
+    assert_eq!(neuron.get_weight_bounds(), bounds);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        1.,
+        SynapticType::Inhibitory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.9);
+    s2.set_measure(0.5);
+
+    // The large learning constant overshoots both maximums
+    neuron.run_cycle(ChargeCycle::Even);
+
+    assert_eq!(neuron.get_weights(), vec![max_weight, 2.]);
+}
+
+#[test]
+fn test_default_neuron_bounds() {
+    let neuron = Neuron::new(1., 8., 3.);
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    s1.set_measure(0.9);
+
+    // 4 + 3 * 4 * 0.9 overshoots 8 by 6.8, and is reflected back
+    neuron.run_cycle(ChargeCycle::Even);
+
+    cmp_f32(neuron.get_weights()[0], 8. - ((4. + (3. * 4. * 0.9)) - 8.));
+}
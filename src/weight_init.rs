@@ -0,0 +1,55 @@
+use crate::rng::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How starting weights are chosen for new synapses.  Weights are
+/// magnitudes, inhibitory ones are only negated as impulses,
+/// so samples that come out negative are clipped to 0
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WeightInit {
+    Constant(f32),
+    /// Uniform in [min, max)
+    Uniform {
+        min: f32,
+        max: f32,
+    },
+    Normal {
+        mean: f32,
+        std_dev: f32,
+    },
+    /// Scaled by the fan-in of the neuron, the number of synapses
+    /// being created on it, so the summed weight into the neuron
+    /// averages total however many inputs it has.  Each weight is
+    /// total / fan_in, times a uniform factor in [1 - spread, 1 + spread)
+    FanIn {
+        total: f32,
+        spread: f32,
+    },
+}
+
+impl WeightInit {
+    /// Samples the weight of one synapse out of fan_in on a neuron
+    pub fn sample(&self, rng: &mut Rng, fan_in: usize) -> f32 {
+        let weight = match *self {
+            WeightInit::Constant(weight) => weight,
+            WeightInit::Uniform { min, max } => rng.uniform(min, max),
+            WeightInit::Normal { mean, std_dev } => rng.normal(mean, std_dev),
+            WeightInit::FanIn { total, spread } => {
+                let mean = total / fan_in.max(1) as f32;
+                mean * rng.uniform(1.0 - spread, 1.0 + spread)
+            }
+        };
+
+        weight.max(0.0)
+    }
+
+    /// Samples the weights of every synapse on a neuron with fan_in synapses,
+    /// for example to pass to Neuron::set_weights
+    pub fn sample_weights(&self, rng: &mut Rng, fan_in: usize) -> Vec<f32> {
+        (0..fan_in).map(|_| self.sample(rng, fan_in)).collect()
+    }
+}
+
+#[cfg(test)]
+mod weight_init_tests;
@@ -0,0 +1,84 @@
+use crate::neuron::{Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::rng::Rng;
+use crate::test_utils::cmp_f32;
+use crate::weight_init::WeightInit;
+use std::rc::Rc;
+
+#[test]
+fn test_constant() {
+    let mut rng = Rng::new(0);
+    assert_eq!(
+        WeightInit::Constant(3.).sample_weights(&mut rng, 3),
+        vec![3., 3., 3.]
+    );
+}
+
+#[test]
+fn test_uniform_and_normal() {
+    let mut rng = Rng::new(0);
+
+    let uniform = WeightInit::Uniform { min: 1., max: 2. };
+    assert!(uniform
+        .sample_weights(&mut rng, 100)
+        .iter()
+        .all(|w| (1. ..2.).contains(w)));
+
+    // Negative samples are clipped
+    let normal = WeightInit::Normal {
+        mean: 0.,
+        std_dev: 1.,
+    };
+    let weights = normal.sample_weights(&mut rng, 100);
+    assert!(weights.iter().all(|w| *w >= 0.));
+    assert!(weights.contains(&0.));
+    assert!(weights.iter().any(|w| *w > 0.));
+}
+
+#[test]
+fn test_fan_in() {
+    let mut rng = Rng::new(0);
+
+    let exact = WeightInit::FanIn {
+        total: 10.,
+        spread: 0.,
+    };
+    for w in exact.sample_weights(&mut rng, 4) {
+        cmp_f32(w, 2.5);
+    }
+
+    let spread = WeightInit::FanIn {
+        total: 10.,
+        spread: 0.5,
+    };
+    let weights = spread.sample_weights(&mut rng, 1000);
+    assert!(weights.iter().all(|w| (0.005..0.015).contains(w)));
+
+    let total: f32 = weights.iter().sum();
+    assert!((total - 10.).abs() < 0.5, "total was {}", total);
+}
+
+#[test]
+fn test_identical_networks() {
+    let build = |seed: u64| {
+        let mut rng = Rng::new(seed);
+        let init = WeightInit::Normal {
+            mean: 4.,
+            std_dev: 1.,
+        };
+
+        let sensor = Rc::new(NeuronicSensor::new());
+        let neuron = Neuron::new(10., 8., 0.1);
+
+        for _ in 0..5 {
+            neuron.create_synapse(
+                init.sample(&mut rng, 5),
+                SynapticType::Excitatory,
+                Rc::clone(&sensor) as Rc<dyn NeuronicInput>,
+            );
+        }
+        neuron.get_weights()
+    };
+
+    assert_eq!(build(3), build(3));
+    assert_ne!(build(3), build(4));
+}
@@ -0,0 +1,89 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the size of a set of weights is measured
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Norm {
+    /// Sum of the weights
+    L1,
+    /// Square root of the sum of the squared weights
+    L2,
+}
+
+impl Norm {
+    pub fn measure(&self, weights: &[f32]) -> f32 {
+        match self {
+            Norm::L1 => weights.iter().map(|weight| weight.abs()).sum(),
+            Norm::L2 => weights
+                .iter()
+                .map(|weight| weight * weight)
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+}
+
+/// Keeps the excitatory weights of a Neuron from all saturating at
+/// max_synapse_weight.  Every weight is multiplied by the same factor,
+/// so the ratios between them, and so the neuron's selectivity, are kept
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WeightNormalization {
+    /// Weights are rescaled after every update so their norm is total
+    Constraint { norm: Norm, total: f32 },
+    /// Synaptic scaling.  Weights move by rate of the way
+    /// towards the scale where their norm is total, so competition
+    /// between inputs builds up slowly over a long run
+    Scaling { norm: Norm, total: f32, rate: f32 },
+}
+
+impl WeightNormalization {
+    pub fn constraint(norm: Norm, total: f32) -> WeightNormalization {
+        check_total(total);
+        WeightNormalization::Constraint { norm, total }
+    }
+
+    /// rate must be in (0, 1]
+    pub fn scaling(norm: Norm, total: f32, rate: f32) -> WeightNormalization {
+        check_total(total);
+
+        if !(0. ..=1.).contains(&rate) || rate == 0. {
+            panic!(
+                "Scaling rate {} out of bounds!  Must be in (0.0, 1.0]",
+                rate
+            );
+        }
+
+        WeightNormalization::Scaling { norm, total, rate }
+    }
+
+    /// The factor every weight should be multiplied by.
+    /// Weights that are all zero can't be scaled, so they're left alone
+    pub fn get_factor(&self, weights: &[f32]) -> f32 {
+        let (norm, total, rate) = match *self {
+            WeightNormalization::Constraint { norm, total } => (norm, total, 1.0),
+            WeightNormalization::Scaling { norm, total, rate } => (norm, total, rate),
+        };
+
+        let current = norm.measure(weights);
+
+        if current <= 0.0 {
+            return 1.0;
+        }
+
+        1.0 + (rate * ((total / current) - 1.0))
+    }
+}
+
+fn check_total(total: f32) {
+    if !total.is_finite() || total <= 0. {
+        panic!(
+            "Weight total {} out of bounds!  Must be positive and finite",
+            total
+        );
+    }
+}
+
+#[cfg(test)]
+mod weight_normalization_tests;
@@ -0,0 +1,154 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType};
+use crate::test_utils::cmp_f32;
+use crate::weight_normalization::{Norm, WeightNormalization};
+use std::rc::Rc;
+
+/// Neuron with a strong input at 0.9 and a weak one at 0.3,
+/// both of which are always active
+fn competing_neuron(neuron: Neuron) -> (Neuron, Rc<NeuronicSensor>, Rc<NeuronicSensor>) {
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+
+    neuron.create_synapse(
+        4.,
+        SynapticType::Excitatory,
+        Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+    );
+    neuron.create_synapse(
+        2.,
+        SynapticType::Excitatory,
+        Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+    );
+
+    s1.set_measure(0.9);
+    s2.set_measure(0.3);
+
+    (neuron, s1, s2)
+}
+
+fn run_cycles(neuron: &Neuron, cycles: usize) {
+    let mut cycle = ChargeCycle::Even;
+
+    for _ in 0..cycles {
+        neuron.run_cycle(cycle);
+        cycle = cycle.next_cycle();
+    }
+}
+
+#[test]
+fn test_norms() {
+    cmp_f32(Norm::L1.measure(&[3., 4.]), 7.);
+    cmp_f32(Norm::L2.measure(&[3., 4.]), 5.);
+}
+
+#[test]
+fn test_factor() {
+    let constraint = WeightNormalization::constraint(Norm::L1, 14.);
+    cmp_f32(constraint.get_factor(&[3., 4.]), 2.);
+
+    let constraint = WeightNormalization::constraint(Norm::L2, 2.5);
+    cmp_f32(constraint.get_factor(&[3., 4.]), 0.5);
+
+    // Scaling only moves part of the way towards the total
+    let scaling = WeightNormalization::scaling(Norm::L1, 14., 0.25);
+    cmp_f32(scaling.get_factor(&[3., 4.]), 1.25);
+
+    // Nothing to scale
+    cmp_f32(constraint.get_factor(&[0., 0.]), 1.);
+    cmp_f32(constraint.get_factor(&[]), 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_scaling_rate_out_of_bounds() {
+    WeightNormalization::scaling(Norm::L1, 1., 0.);
+}
+
+#[test]
+#[should_panic]
+fn test_total_out_of_bounds() {
+    WeightNormalization::constraint(Norm::L2, -1.);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_total() {
+    WeightNormalization::constraint(Norm::L1, f32::NAN);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_rate() {
+    WeightNormalization::scaling(Norm::L1, 6., f32::NAN);
+}
+
+#[test]
+fn test_saturation_without_normalization() {
+    let (neuron, _s1, _s2) = competing_neuron(Neuron::new(1., 8., 0.1));
+
+    run_cycles(&neuron, 300);
+
+    // Both inputs end up at the max, so the neuron no longer prefers either
+    let weights = neuron.get_weights();
+    assert!(weights[0] > 7.9);
+    assert!(weights[1] > 7.9);
+}
+
+#[test]
+fn test_constraint_keeps_selectivity() {
+    let (neuron, _s1, _s2) = competing_neuron(
+        Neuron::new(1., 8., 0.1)
+            .with_weight_normalization(WeightNormalization::constraint(Norm::L1, 6.)),
+    );
+
+    run_cycles(&neuron, 300);
+
+    let weights = neuron.get_weights();
+    cmp_f32(weights[0] + weights[1], 6.);
+    assert!(weights[0] > weights[1]);
+}
+
+#[test]
+fn test_scaling() {
+    let (neuron, _s1, _s2) = competing_neuron(
+        Neuron::new(1., 8., 0.1).with_weight_normalization(WeightNormalization::scaling(
+            Norm::L1,
+            4.,
+            0.5,
+        )),
+    );
+
+    neuron.run_cycle(ChargeCycle::Even);
+
+    // Learning moves the weights to 4.36 and 2.18, then scaling
+    // moves their sum halfway from 6.54 back to 4
+    let learned = [4. + 0.36, 2. + 0.18];
+    let factor = 1. + (0.5 * ((4. / (learned[0] + learned[1])) - 1.));
+
+    let weights = neuron.get_weights();
+    cmp_f32(weights[0], learned[0] * factor);
+    cmp_f32(weights[1], learned[1] * factor);
+}
+
+#[test]
+fn test_normalization_skips_other_synapses() {
+    let neuron = Neuron::new(1., 8., 0.)
+        .with_weight_normalization(WeightNormalization::constraint(Norm::L1, 4.));
+
+    let s1 = Rc::new(NeuronicSensor::new());
+    for synaptic_type in [
+        SynapticType::Excitatory,
+        SynapticType::Excitatory,
+        SynapticType::Inhibitory,
+        SynapticType::Modulatory,
+    ]
+    .iter()
+    {
+        neuron.create_synapse(2., *synaptic_type, Rc::clone(&s1) as Rc<dyn NeuronicInput>);
+    }
+    neuron.set_synapse_frozen(1, true);
+
+    neuron.normalize_weights();
+
+    assert_eq!(neuron.get_weights(), vec![4., 2., 2., 2.]);
+}
@@ -0,0 +1,139 @@
+use crate::neuron::{ChargeCycle, Neuron, Neuronic, NeuronicInput, SynapticType};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A group of neurons with implicit lateral inhibition.  After the
+/// neurons run, only the k neurons with the largest measures keep
+/// their measure, and the rest are zeroed.  Only those winners
+/// update their synapses, so the group learns competitively without
+/// needing n² inhibitory synapses.
+///
+/// The layer is itself Neuronic, so it can be added to a Network,
+/// and creating a synapse on the layer creates it on every neuron
+pub struct WtaLayer {
+    neurons: Vec<Rc<Neuron>>,
+    k: usize,
+    winners: RefCell<Vec<bool>>,
+}
+
+impl WtaLayer {
+    pub fn new(neurons: Vec<Rc<Neuron>>, k: usize) -> WtaLayer {
+        if k == 0 {
+            panic!("A WtaLayer needs at least one winner");
+        }
+
+        let winners = RefCell::new(vec![false; neurons.len()]);
+
+        WtaLayer {
+            neurons,
+            k,
+            winners,
+        }
+    }
+
+    pub fn get_neurons(&self) -> &[Rc<Neuron>] {
+        &self.neurons
+    }
+
+    pub fn get_k(&self) -> usize {
+        self.k
+    }
+
+    /// Whether each neuron won the most recent cycle
+    pub fn get_winners(&self) -> Vec<bool> {
+        self.winners.borrow().clone()
+    }
+
+    /// Zeroes every measure except the k largest.  Neurons that
+    /// didn't fire, or whose measure is NaN, can't win.
+    /// Ties go to the neuron added first
+    fn inhibit(&self, cycle: ChargeCycle) {
+        let measures: Vec<f32> = self
+            .neurons
+            .iter()
+            .map(|neuron| neuron.get_measure(cycle))
+            .map(|measure| if measure.is_nan() { 0.0 } else { measure })
+            .collect();
+
+        let mut ranking: Vec<usize> = (0..measures.len()).collect();
+        ranking.sort_by(|a, b| measures[*b].total_cmp(&measures[*a]));
+
+        let mut winners = self.winners.borrow_mut();
+
+        for (rank, index) in ranking.into_iter().enumerate() {
+            winners[index] = rank < self.k && measures[index] > 0.0;
+
+            if !winners[index] {
+                self.neurons[index].set_measure(cycle, 0.0);
+            }
+        }
+    }
+}
+
+impl NeuronicInput for WtaLayer {
+    /// The largest measure in the layer
+    fn get_measure(&self, cycle: ChargeCycle) -> f32 {
+        self.neurons
+            .iter()
+            .map(|neuron| neuron.get_measure(cycle))
+            .fold(0.0, f32::max)
+    }
+}
+
+impl Neuronic for WtaLayer {
+    /// Runs every neuron and then applies the inhibition,
+    /// returning the largest measure in the layer
+    fn run_static_cycle(&self, cycle: ChargeCycle) -> f32 {
+        for neuron in self.neurons.iter() {
+            neuron.run_static_cycle(cycle);
+        }
+
+        self.inhibit(cycle);
+        self.get_measure(cycle)
+    }
+
+    /// Only the winners of the last cycle learn, but every neuron
+    /// still counts the cycle towards its homeostasis, if it has any
+    fn update_synapses(&self, cycle: ChargeCycle) {
+        let winners = self.winners.borrow();
+
+        for (neuron, winner) in self.neurons.iter().zip(winners.iter()) {
+            if *winner {
+                neuron.update_synapses(cycle);
+            } else {
+                neuron.update_threshold(cycle);
+            }
+        }
+    }
+
+    fn clear(&self) {
+        for neuron in self.neurons.iter() {
+            neuron.clear();
+        }
+
+        for winner in self.winners.borrow_mut().iter_mut() {
+            *winner = false;
+        }
+    }
+
+    fn create_synapse(
+        &self,
+        starting_weight: f32,
+        synaptic_type: SynapticType,
+        input: Rc<dyn NeuronicInput>,
+    ) {
+        for neuron in self.neurons.iter() {
+            neuron.create_synapse(starting_weight, synaptic_type, Rc::clone(&input));
+        }
+    }
+
+    fn get_synapse_inputs(&self) -> Vec<(Rc<dyn NeuronicInput>, SynapticType)> {
+        self.neurons
+            .iter()
+            .flat_map(|neuron| neuron.get_synapse_inputs())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod wta_tests;
@@ -0,0 +1,146 @@
+use crate::network::Network;
+use crate::neuron::{
+    ChargeCycle, Homeostasis, Neuron, Neuronic, NeuronicInput, NeuronicSensor, SynapticType,
+};
+use crate::test_utils::cmp_f32;
+use crate::wta::WtaLayer;
+use std::rc::Rc;
+
+/// Builds three neurons reading from two sensors.  With the sensors
+/// at 0.9 and 0.6, the first neuron fires 0.6, the second fires 0.9,
+/// and the third never reaches its threshold
+fn build_neurons() -> (Rc<NeuronicSensor>, Rc<NeuronicSensor>, Vec<Rc<Neuron>>) {
+    let s1 = Rc::new(NeuronicSensor::new());
+    let s2 = Rc::new(NeuronicSensor::new());
+
+    let weights = [(6., 6.), (12., 1.), (1., 1.)];
+
+    let neurons = weights
+        .iter()
+        .map(|(s1_weight, s2_weight)| {
+            let neuron = Rc::new(Neuron::new(10., 16., 0.1));
+            neuron.create_synapse(
+                *s1_weight,
+                SynapticType::Excitatory,
+                Rc::clone(&s1) as Rc<dyn NeuronicInput>,
+            );
+            neuron.create_synapse(
+                *s2_weight,
+                SynapticType::Excitatory,
+                Rc::clone(&s2) as Rc<dyn NeuronicInput>,
+            );
+            neuron
+        })
+        .collect();
+
+    s1.set_measure(0.9);
+    s2.set_measure(0.6);
+
+    (s1, s2, neurons)
+}
+
+#[test]
+fn test_single_winner() {
+    let (_s1, _s2, neurons) = build_neurons();
+    let layer = WtaLayer::new(neurons.clone(), 1);
+
+    let weights_before: Vec<Vec<f32>> = neurons.iter().map(|n| n.get_weights()).collect();
+
+    cmp_f32(layer.run_cycle(ChargeCycle::Even), 0.9);
+
+    cmp_f32(neurons[0].get_measure(ChargeCycle::Even), 0.0);
+    cmp_f32(neurons[1].get_measure(ChargeCycle::Even), 0.9);
+    cmp_f32(neurons[2].get_measure(ChargeCycle::Even), 0.0);
+
+    assert_eq!(layer.get_winners(), vec![false, true, false]);
+
+    // Only the winner learned
+    assert_eq!(neurons[0].get_weights(), weights_before[0]);
+    assert_ne!(neurons[1].get_weights(), weights_before[1]);
+    assert_eq!(neurons[2].get_weights(), weights_before[2]);
+}
+
+#[test]
+fn test_k_winners() {
+    let (_s1, _s2, neurons) = build_neurons();
+    let layer = WtaLayer::new(neurons.clone(), 2);
+
+    layer.run_static_cycle(ChargeCycle::Odd);
+
+    cmp_f32(neurons[0].get_measure(ChargeCycle::Odd), 0.6);
+    cmp_f32(neurons[1].get_measure(ChargeCycle::Odd), 0.9);
+    cmp_f32(neurons[2].get_measure(ChargeCycle::Odd), 0.0);
+
+    // The third neuron never fired, so it can't win even with k = 2
+    assert_eq!(layer.get_winners(), vec![true, true, false]);
+
+    layer.clear();
+
+    assert_eq!(layer.get_winners(), vec![false, false, false]);
+    cmp_f32(neurons[1].get_measure(ChargeCycle::Odd), 0.0);
+}
+
+#[test]
+fn test_layer_in_network() {
+    let (_s1, _s2, neurons) = build_neurons();
+    let layer = Rc::new(WtaLayer::new(neurons.clone(), 1));
+
+    // Synapses created on the layer go to every neuron
+    let bias = Rc::new(NeuronicSensor::new());
+    layer.create_synapse(1., SynapticType::Excitatory, bias as Rc<dyn NeuronicInput>);
+
+    for neuron in layer.get_neurons() {
+        assert_eq!(neuron.get_synapse_count(), 3);
+    }
+
+    let mut network = Network::new();
+    network.add_neuron(Rc::clone(&layer) as Rc<dyn Neuronic>);
+    network.run_cycle();
+
+    cmp_f32(layer.get_measure(ChargeCycle::Even), 0.9);
+    assert_eq!(layer.get_winners(), vec![false, true, false]);
+}
+
+#[test]
+fn test_frozen_loser_keeps_threshold() {
+    let (_s1, _s2, neurons) = build_neurons();
+
+    let neurons: Vec<Rc<Neuron>> = neurons
+        .into_iter()
+        .map(|neuron| {
+            let neuron = Rc::try_unwrap(neuron).ok().unwrap();
+            Rc::new(neuron.with_homeostasis(Homeostasis::new(0.5, 0.5, 2.)))
+        })
+        .collect();
+    neurons[0].set_frozen(true);
+
+    let layer = WtaLayer::new(neurons.clone(), 1);
+    layer.run_cycle(ChargeCycle::Even);
+
+    // Both lost, but only the neuron that isn't frozen adapted
+    assert_eq!(layer.get_winners(), vec![false, true, false]);
+    cmp_f32(neurons[0].get_fire_threshold(), 10.);
+    assert!(neurons[2].get_fire_threshold() < 10.);
+}
+
+#[test]
+fn test_nan_measure_loses() {
+    let (_s1, _s2, neurons) = build_neurons();
+    let layer = WtaLayer::new(neurons.clone(), 2);
+
+    neurons[0].set_measure(ChargeCycle::Even, f32::NAN);
+    neurons[1].set_measure(ChargeCycle::Even, 0.9);
+    neurons[2].set_measure(ChargeCycle::Even, 0.3);
+    layer.inhibit(ChargeCycle::Even);
+
+    assert_eq!(layer.get_winners(), vec![false, true, true]);
+    cmp_f32(neurons[0].get_measure(ChargeCycle::Even), 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_no_winners() {
+    let (_s1, _s2, neurons) = build_neurons();
+
+    WtaLayer::new(neurons, 0);
+}